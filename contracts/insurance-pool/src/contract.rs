@@ -0,0 +1,173 @@
+use soroban_sdk::{contract, contractimpl, Address, Env};
+use crate::interface::contract::InsurancePoolContractTrait;
+use crate::events::pool::{ClaimPaid, LiquidityDeposited, LiquidityWithdrawn, PremiumCollected, SharesWrittenOff};
+use crate::methods::{
+    shares::{assets_for_shares, release_reserved, shares_for_deposit, write_off_drained_shares},
+    token::token_transfer,
+};
+use crate::storage::{
+    admin::{read_admin, read_rent_a_car, write_admin, write_rent_a_car},
+    pool::{read_pool, write_pool},
+    provider::{read_shares, write_shares},
+    structs::pool_state::PoolState,
+    token::write_token,
+    types::errors::InsurancePoolError,
+};
+
+#[contract]
+pub struct InsurancePoolContract;
+
+#[contractimpl]
+impl InsurancePoolContractTrait for InsurancePoolContract {
+    fn __constructor(env: &Env, admin: Address, token: Address) {
+        write_admin(env, &admin);
+        write_token(env, &token);
+    }
+
+    fn get_admin(env: &Env) -> Address {
+        read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"))
+    }
+
+    fn set_rent_a_car(env: &Env, rent_a_car: Address) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        write_rent_a_car(env, &rent_a_car);
+    }
+
+    fn get_rent_a_car(env: &Env) -> Address {
+        read_rent_a_car(env).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    fn deposit(env: &Env, provider: Address, amount: i128) -> i128 {
+        provider.require_auth();
+
+        if amount <= 0 {
+            panic!("{:?}", InsurancePoolError::InvalidAmount);
+        }
+
+        let mut pool = read_pool(env);
+        let written_off = write_off_drained_shares(&mut pool);
+        if written_off > 0 {
+            SharesWrittenOff { epoch: pool.epoch, shares: written_off }.publish(env);
+        }
+        let shares = shares_for_deposit(&pool, amount).unwrap_or_else(|e| panic!("{:?}", e));
+        if shares == 0 {
+            panic!("{:?}", InsurancePoolError::InvalidAmount);
+        }
+
+        pool.total_assets += amount;
+        pool.total_shares += shares;
+        write_pool(env, &pool);
+        write_shares(env, pool.epoch, &provider, read_shares(env, pool.epoch, &provider) + shares);
+
+        token_transfer(env, &provider, &env.current_contract_address(), &amount);
+
+        LiquidityDeposited { provider, amount, shares }.publish(env);
+        shares
+    }
+
+    fn withdraw(env: &Env, provider: Address, shares: i128) -> i128 {
+        provider.require_auth();
+
+        if shares <= 0 {
+            panic!("{:?}", InsurancePoolError::InvalidAmount);
+        }
+        let mut pool = read_pool(env);
+        let held = read_shares(env, pool.epoch, &provider);
+        if shares > held {
+            panic!("{:?}", InsurancePoolError::InsufficientShares);
+        }
+
+        let amount = assets_for_shares(&pool, shares);
+        // Coverage already sold stays backed until its rentals are settled
+        if amount > pool.total_assets - pool.reserved {
+            panic!("{:?}", InsurancePoolError::InsufficientLiquidity);
+        }
+
+        pool.total_assets -= amount;
+        pool.total_shares -= shares;
+        write_pool(env, &pool);
+        write_shares(env, pool.epoch, &provider, held - shares);
+
+        if amount > 0 {
+            token_transfer(env, &env.current_contract_address(), &provider, &amount);
+        }
+
+        LiquidityWithdrawn { provider, amount, shares }.publish(env);
+        amount
+    }
+
+    fn get_shares(env: &Env, provider: Address) -> i128 {
+        read_shares(env, read_pool(env).epoch, &provider)
+    }
+
+    fn get_provider_value(env: &Env, provider: Address) -> i128 {
+        let pool = read_pool(env);
+        assets_for_shares(&pool, read_shares(env, pool.epoch, &provider))
+    }
+
+    fn get_pool(env: &Env) -> PoolState {
+        read_pool(env)
+    }
+
+    fn collect_premium(env: &Env, payer: Address, amount: i128, coverage: i128) {
+        // Only rentals booked through the linked contract count as coverage
+        let rent_a_car = read_rent_a_car(env).unwrap_or_else(|e| panic!("{:?}", e));
+        rent_a_car.require_auth();
+
+        if amount < 0 || coverage < 0 || (amount == 0 && coverage == 0) {
+            panic!("{:?}", InsurancePoolError::InvalidAmount);
+        }
+
+        // Premiums raise the value of every share, that is the providers' yield
+        let mut pool = read_pool(env);
+        pool.total_assets += amount;
+        pool.premiums_collected += amount;
+        pool.reserved += coverage;
+        write_pool(env, &pool);
+
+        if amount > 0 {
+            token_transfer(env, &payer, &env.current_contract_address(), &amount);
+        }
+
+        PremiumCollected { payer, amount, coverage }.publish(env);
+    }
+
+    fn pay_claim(env: &Env, to: Address, amount: i128, coverage: i128) -> i128 {
+        let rent_a_car = read_rent_a_car(env).unwrap_or_else(|e| panic!("{:?}", e));
+        rent_a_car.require_auth();
+
+        if amount <= 0 || amount > coverage {
+            panic!("{:?}", InsurancePoolError::InvalidAmount);
+        }
+
+        // A short pool pays what it has, the caller decides who covers the rest
+        let mut pool = read_pool(env);
+        release_reserved(&mut pool, coverage);
+        let paid = amount.min(pool.total_assets);
+        pool.total_assets -= paid;
+        pool.claims_paid += paid;
+        write_pool(env, &pool);
+
+        if paid > 0 {
+            token_transfer(env, &env.current_contract_address(), &to, &paid);
+        }
+
+        ClaimPaid { to, requested: amount, paid }.publish(env);
+        paid
+    }
+
+    fn release_coverage(env: &Env, coverage: i128) {
+        let rent_a_car = read_rent_a_car(env).unwrap_or_else(|e| panic!("{:?}", e));
+        rent_a_car.require_auth();
+
+        if coverage <= 0 {
+            panic!("{:?}", InsurancePoolError::InvalidAmount);
+        }
+
+        let mut pool = read_pool(env);
+        release_reserved(&mut pool, coverage);
+        write_pool(env, &pool);
+    }
+}
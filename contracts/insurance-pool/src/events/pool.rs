@@ -0,0 +1,50 @@
+use soroban_sdk::{contractevent, Address};
+
+/// Emitted when a liquidity provider adds funds to the pool
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiquidityDeposited {
+    #[topic]
+    pub provider: Address,
+    pub amount: i128,
+    pub shares: i128,
+}
+
+/// Emitted when a liquidity provider redeems shares
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiquidityWithdrawn {
+    #[topic]
+    pub provider: Address,
+    pub amount: i128,
+    pub shares: i128,
+}
+
+/// Emitted when a renter's coverage premium is paid into the pool and the coverage reserved
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PremiumCollected {
+    #[topic]
+    pub payer: Address,
+    pub amount: i128,
+    pub coverage: i128,
+}
+
+/// Emitted when the pool covers an approved damage claim
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimPaid {
+    #[topic]
+    pub to: Address,
+    pub requested: i128,
+    pub paid: i128,
+}
+
+/// Emitted when a deposit refills a drained pool and the worthless shares left over are written off
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SharesWrittenOff {
+    #[topic]
+    pub epoch: u32,
+    pub shares: i128,
+}
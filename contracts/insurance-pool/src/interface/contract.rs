@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::structs::pool_state::PoolState;
+
+pub trait InsurancePoolContractTrait {
+    fn __constructor(env: &Env, admin: Address, token: Address);
+    fn get_admin(env: &Env) -> Address;
+    fn set_rent_a_car(env: &Env, rent_a_car: Address);
+    fn get_rent_a_car(env: &Env) -> Address;
+
+    // Liquidity providers
+    fn deposit(env: &Env, provider: Address, amount: i128) -> i128;
+    fn withdraw(env: &Env, provider: Address, shares: i128) -> i128;
+    fn get_shares(env: &Env, provider: Address) -> i128;
+    fn get_provider_value(env: &Env, provider: Address) -> i128;
+    fn get_pool(env: &Env) -> PoolState;
+
+    // Rent-a-car integration
+    fn collect_premium(env: &Env, payer: Address, amount: i128, coverage: i128);
+    fn pay_claim(env: &Env, to: Address, amount: i128, coverage: i128) -> i128;
+    fn release_coverage(env: &Env, coverage: i128);
+}
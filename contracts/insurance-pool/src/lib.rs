@@ -0,0 +1,10 @@
+#![no_std]
+
+mod contract;
+mod events;
+mod interface;
+mod storage;
+mod methods;
+mod tests;
+
+pub use contract::{InsurancePoolContract, InsurancePoolContractClient};
@@ -0,0 +1,2 @@
+pub mod shares;
+pub mod token;
@@ -0,0 +1,40 @@
+use crate::storage::{structs::pool_state::PoolState, types::errors::InsurancePoolError};
+
+/// Writes off the shares of a pool that claims drained to zero assets, they are worth nothing.
+/// Starts a new epoch so the next deposit mints at one share per token again. Returns the shares written off.
+pub(crate) fn write_off_drained_shares(pool: &mut PoolState) -> i128 {
+    if pool.total_assets != 0 || pool.total_shares == 0 {
+        return 0;
+    }
+    let written_off = pool.total_shares;
+    pool.total_shares = 0;
+    pool.epoch += 1;
+    written_off
+}
+
+/// Shares minted for depositing `amount`; the first deposit sets the price at one share per token
+pub(crate) fn shares_for_deposit(pool: &PoolState, amount: i128) -> Result<i128, InsurancePoolError> {
+    if pool.total_shares == 0 {
+        return Ok(amount);
+    }
+    amount
+        .checked_mul(pool.total_shares)
+        .map(|scaled| scaled / pool.total_assets)
+        .ok_or(InsurancePoolError::InvalidAmount)
+}
+
+/// Assets `shares` are worth, rounded down in favour of the remaining providers
+pub(crate) fn assets_for_shares(pool: &PoolState, shares: i128) -> i128 {
+    if pool.total_shares == 0 {
+        return 0;
+    }
+    shares
+        .checked_mul(pool.total_assets)
+        .unwrap_or_else(|| panic!("Share value overflow"))
+        / pool.total_shares
+}
+
+/// Frees `coverage` once the rental it was sold for is settled; coverage sold before it was tracked is not reserved
+pub(crate) fn release_reserved(pool: &mut PoolState, coverage: i128) {
+    pool.reserved = (pool.reserved - coverage).max(0);
+}
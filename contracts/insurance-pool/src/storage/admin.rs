@@ -0,0 +1,26 @@
+use soroban_sdk::{Address, Env};
+
+use super::types::storage::DataKey;
+use super::types::errors::InsurancePoolError;
+
+pub(crate) fn read_admin(env: &Env) -> Result<Address, InsurancePoolError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(InsurancePoolError::NotInitialized)
+}
+
+pub(crate) fn write_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub(crate) fn read_rent_a_car(env: &Env) -> Result<Address, InsurancePoolError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RentACar)
+        .ok_or(InsurancePoolError::RentACarNotSet)
+}
+
+pub(crate) fn write_rent_a_car(env: &Env, rent_a_car: &Address) {
+    env.storage().instance().set(&DataKey::RentACar, rent_a_car);
+}
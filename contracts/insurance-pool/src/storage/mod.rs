@@ -0,0 +1,6 @@
+pub mod admin;
+pub mod pool;
+pub mod provider;
+pub mod structs;
+pub mod token;
+pub mod types;
@@ -0,0 +1,14 @@
+use soroban_sdk::Env;
+
+use crate::storage::{structs::pool_state::PoolState, types::storage::DataKey};
+
+pub(crate) fn read_pool(env: &Env) -> PoolState {
+    env.storage()
+        .instance()
+        .get(&DataKey::Pool)
+        .unwrap_or_default()
+}
+
+pub(crate) fn write_pool(env: &Env, pool: &PoolState) {
+    env.storage().instance().set(&DataKey::Pool, pool);
+}
@@ -0,0 +1,20 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+/// Shares `provider` holds in the pool's current `epoch`; shares from earlier epochs were written off
+pub(crate) fn read_shares(env: &Env, epoch: u32, provider: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Shares(epoch, provider.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_shares(env: &Env, epoch: u32, provider: &Address, shares: i128) {
+    let key = DataKey::Shares(epoch, provider.clone());
+    if shares == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &shares);
+    }
+}
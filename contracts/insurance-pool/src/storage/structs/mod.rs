@@ -0,0 +1 @@
+pub mod pool_state;
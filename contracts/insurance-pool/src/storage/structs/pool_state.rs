@@ -0,0 +1,13 @@
+use soroban_sdk::contracttype;
+
+/// Pool accounting; providers own `total_assets` in proportion to their shares
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct PoolState {
+    pub total_assets: i128,       // Liquidity plus premiums minus claims paid
+    pub total_shares: i128,       // Shares issued to liquidity providers
+    pub premiums_collected: i128, // All-time premiums received
+    pub claims_paid: i128,        // All-time damage claims paid out
+    pub reserved: i128,           // Coverage sold on rentals that can still be claimed, withdrawals leave it in the pool
+    pub epoch: u32,               // Bumped when a drained pool is refilled, older shares are written off
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+use crate::storage::types::errors::InsurancePoolError;
+
+pub(crate) fn read_token(env: &Env) -> Result<Address, InsurancePoolError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Token)
+        .ok_or(InsurancePoolError::NotInitialized)
+}
+
+pub(crate) fn write_token(env: &Env, token: &Address) {
+    env.storage().instance().set(&DataKey::Token, token);
+}
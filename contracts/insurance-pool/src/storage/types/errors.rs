@@ -0,0 +1,15 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum InsurancePoolError {
+    // General errors
+    NotInitialized = 1,       // Admin or token not set
+    RentACarNotSet = 2,       // Admin has not linked the rent-a-car contract yet
+
+    // Validation errors
+    InvalidAmount = 10,       // Amount or shares zero or negative
+    InsufficientShares = 11,  // Withdrawing more shares than the provider holds
+    InsufficientLiquidity = 13, // Withdrawal would leave coverage already sold unbacked
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,             // dirección del administrador del pool
+    Token,             // token en el que se pagan primas, depósitos y reclamos
+    RentACar,          // contrato de alquiler autorizado a cobrar primas y pagar reclamos
+    Pool,              // activos, acciones y totales históricos del pool
+    Shares(u32, Address), // acciones de un proveedor de liquidez en una época del pool
+}
@@ -0,0 +1,147 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use crate::{contract::InsurancePoolContractClient, InsurancePoolContract};
+use crate::tests::config::{contract::ContractTest, utils::create_token_contract};
+
+#[test]
+pub fn test_admin_links_rent_a_car() {
+    let ContractTest { contract, admin, rent_a_car, .. } = ContractTest::setup();
+
+    assert_eq!(contract.get_admin(), admin);
+    assert_eq!(contract.get_rent_a_car(), rent_a_car);
+}
+
+#[test]
+pub fn test_claim_paid_from_pool() {
+    let ContractTest { env, contract, rent_a_car, token, .. } = ContractTest::setup();
+
+    let provider = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&provider, &10_000_i128);
+    contract.deposit(&provider, &2_000_i128);
+
+    assert_eq!(contract.pay_claim(&rent_a_car, &500_i128, &500_i128), 500);
+    assert_eq!(token_client.balance(&rent_a_car), 500);
+
+    let pool = contract.get_pool();
+    assert_eq!(pool.total_assets, 1_500);
+    assert_eq!(pool.claims_paid, 500);
+
+    // Claims are a loss spread over every share
+    assert_eq!(contract.get_provider_value(&provider), 1_500);
+}
+
+#[test]
+pub fn test_short_pool_pays_what_it_has() {
+    let ContractTest { env, contract, rent_a_car, token, .. } = ContractTest::setup();
+
+    let provider = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&provider, &10_000_i128);
+    contract.deposit(&provider, &300_i128);
+
+    assert_eq!(contract.pay_claim(&rent_a_car, &500_i128, &500_i128), 300);
+    assert_eq!(token_client.balance(&rent_a_car), 300);
+    assert_eq!(contract.get_pool().total_assets, 0);
+}
+
+#[test]
+pub fn test_drained_pool_can_be_refilled() {
+    let ContractTest { env, contract, rent_a_car, token, .. } = ContractTest::setup();
+
+    let provider = Address::generate(&env);
+    let new_provider = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&provider, &10_000_i128);
+    token_admin.mint(&new_provider, &10_000_i128);
+    contract.deposit(&provider, &300_i128);
+    contract.pay_claim(&rent_a_car, &300_i128, &300_i128);
+
+    // The first deposit into the empty pool mints one share per token again
+    assert_eq!(contract.deposit(&new_provider, &500_i128), 500);
+    let pool = contract.get_pool();
+    assert_eq!(pool.total_assets, 500);
+    assert_eq!(pool.total_shares, 500);
+    assert_eq!(pool.epoch, 1);
+
+    // The drained shares were written off, they hold no claim on the new liquidity
+    assert_eq!(contract.get_shares(&provider), 0);
+    assert_eq!(contract.get_provider_value(&provider), 0);
+    assert_eq!(contract.get_provider_value(&new_provider), 500);
+
+    assert_eq!(contract.withdraw(&new_provider, &500_i128), 500);
+    assert_eq!(token_client.balance(&new_provider), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "RentACarNotSet")]
+pub fn test_claims_require_linked_rent_a_car() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (token_client, _) = create_token_contract(&env, &admin);
+    let contract_id = env.register(InsurancePoolContract, (&admin, &token_client.address));
+    let contract = InsurancePoolContractClient::new(&env, &contract_id);
+
+    contract.pay_claim(&admin, &100_i128, &100_i128);
+}
+
+#[test]
+#[should_panic]
+pub fn test_only_rent_a_car_collects_premiums() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &1_000_i128);
+
+    // Only the renter authorizes, the linked contract does not
+    env.mock_auths(&[]);
+    contract.collect_premium(&renter, &100_i128, &1_000_i128);
+}
+
+#[test]
+#[should_panic(expected = "InsufficientLiquidity")]
+pub fn test_withdrawals_keep_sold_coverage_backed() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let provider = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&provider, &10_000_i128);
+    token_admin.mint(&renter, &1_000_i128);
+
+    env.mock_all_auths_allowing_non_root_auth();
+    contract.deposit(&provider, &2_000_i128);
+    contract.collect_premium(&renter, &100_i128, &1_500_i128);
+    assert_eq!(contract.get_pool().reserved, 1_500);
+
+    // 2100 in the pool, 1500 of it backs the coverage sold
+    assert_eq!(contract.withdraw(&provider, &500_i128), 525);
+    contract.withdraw(&provider, &100_i128);
+}
+
+#[test]
+pub fn test_settled_coverage_is_released() {
+    let ContractTest { env, contract, rent_a_car, token, .. } = ContractTest::setup();
+
+    let provider = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&provider, &10_000_i128);
+    token_admin.mint(&renter, &1_000_i128);
+
+    env.mock_all_auths_allowing_non_root_auth();
+    contract.deposit(&provider, &2_000_i128);
+    contract.collect_premium(&renter, &100_i128, &1_500_i128);
+    contract.collect_premium(&renter, &100_i128, &1_500_i128);
+
+    // A claim releases the whole coverage of its rental, not only what it paid
+    assert_eq!(contract.pay_claim(&rent_a_car, &200_i128, &1_500_i128), 200);
+    assert_eq!(contract.get_pool().reserved, 1_500);
+
+    // Rentals that end without a claim release theirs too
+    contract.release_coverage(&1_500_i128);
+    assert_eq!(contract.get_pool().reserved, 0);
+    assert_eq!(contract.withdraw(&provider, &2_000_i128), 2_000);
+}
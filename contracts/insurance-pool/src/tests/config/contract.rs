@@ -0,0 +1,40 @@
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+use crate::{contract::InsurancePoolContractClient, InsurancePoolContract};
+use crate::tests::config::utils::create_token_contract;
+
+pub struct ContractTest<'a> {
+    pub env: Env,
+    pub contract: InsurancePoolContractClient<'a>,
+    pub address: Address,
+    pub admin: Address,
+    /// Stands in for the rent-a-car contract linked to the pool
+    pub rent_a_car: Address,
+    pub token: (token::Client<'a>, token::StellarAssetClient<'a>, Address),
+}
+
+impl<'a> ContractTest<'a> {
+    pub fn setup() -> Self {
+        let env = Env::default();
+
+        let admin = Address::generate(&env);
+        let rent_a_car = Address::generate(&env);
+        let token_issuer = Address::generate(&env);
+
+        let (token_client, token_admin) = create_token_contract(&env, &token_issuer);
+
+        let contract_id = env.register(InsurancePoolContract, (&admin, &token_client.address));
+        let contract = InsurancePoolContractClient::new(&env, &contract_id);
+
+        env.mock_all_auths();
+        contract.set_rent_a_car(&rent_a_car);
+
+        ContractTest {
+            env,
+            contract,
+            address: contract_id,
+            admin,
+            rent_a_car,
+            token: (token_client, token_admin, token_issuer),
+        }
+    }
+}
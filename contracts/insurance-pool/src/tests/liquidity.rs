@@ -0,0 +1,88 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+pub fn test_first_deposit_mints_one_share_per_token() {
+    let ContractTest { env, contract, address, token, .. } = ContractTest::setup();
+
+    let provider = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&provider, &10_000_i128);
+
+    assert_eq!(contract.deposit(&provider, &4_000_i128), 4_000);
+    assert_eq!(contract.get_shares(&provider), 4_000);
+    assert_eq!(token_client.balance(&address), 4_000);
+
+    let pool = contract.get_pool();
+    assert_eq!(pool.total_assets, 4_000);
+    assert_eq!(pool.total_shares, 4_000);
+}
+
+#[test]
+pub fn test_premiums_are_shared_by_providers() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&alice, &10_000_i128);
+    token_admin.mint(&bob, &10_000_i128);
+    token_admin.mint(&renter, &1_000_i128);
+
+    // The renter's transfer is authorized under the rental call in rent-a-car
+    env.mock_all_auths_allowing_non_root_auth();
+    contract.deposit(&alice, &3_000_i128);
+    contract.deposit(&bob, &1_000_i128);
+    contract.collect_premium(&renter, &400_i128, &0_i128);
+
+    assert_eq!(contract.get_pool().premiums_collected, 400);
+    assert_eq!(contract.get_provider_value(&alice), 3_300);
+    assert_eq!(contract.get_provider_value(&bob), 1_100);
+
+    // Later providers buy in at the higher share price
+    let carol = Address::generate(&env);
+    token_admin.mint(&carol, &1_100_i128);
+    assert_eq!(contract.deposit(&carol, &1_100_i128), 1_000);
+
+    assert_eq!(contract.withdraw(&alice, &3_000_i128), 3_300);
+    assert_eq!(token_client.balance(&alice), 10_300);
+    assert_eq!(contract.get_shares(&alice), 0);
+}
+
+#[test]
+pub fn test_partial_withdraw() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let provider = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&provider, &10_000_i128);
+
+    contract.deposit(&provider, &5_000_i128);
+    assert_eq!(contract.withdraw(&provider, &2_000_i128), 2_000);
+
+    assert_eq!(contract.get_shares(&provider), 3_000);
+    assert_eq!(token_client.balance(&provider), 7_000);
+}
+
+#[test]
+#[should_panic(expected = "InsufficientShares")]
+pub fn test_cannot_withdraw_more_than_held() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let provider = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&provider, &10_000_i128);
+
+    contract.deposit(&provider, &1_000_i128);
+    contract.withdraw(&provider, &1_001_i128);
+}
+
+#[test]
+#[should_panic(expected = "InvalidAmount")]
+pub fn test_deposit_must_be_positive() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let provider = Address::generate(&env);
+    contract.deposit(&provider, &0_i128);
+}
@@ -0,0 +1,5 @@
+#![cfg(test)]
+
+pub mod config;
+pub mod liquidity;
+pub mod claims;
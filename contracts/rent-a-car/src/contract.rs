@@ -1,21 +1,101 @@
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+use stellar_tokens::non_fungible::Base;
 use crate::interface::contract::RentACarContractTrait;
 use crate::storage::{
     admin::{write_admin, read_admin, has_admin},
-    token::{write_token, read_token},
+    token::write_token,
     car::{write_car, read_car, remove_car as remove_car_storage, has_car as storage_has_car},
-    rental::{write_rental, read_rental, remove_rental, has_rental as storage_has_rental},
+    rental::{write_rental, read_rental, remove_rental, has_rental as storage_has_rental, write_car_renter, remove_car_renter},
+    driver_fee::{read_driver_fee, write_driver_fee, remove_driver_fee},
     contract_balance::{read_contract_balance, write_contract_balance},
     commission::{read_commission, write_commission, read_admin_commission_balance, write_admin_commission_balance},
-    car_owners::{add_car_owner, remove_car_owner, get_car_owners},
+    insurance::{read_insurance_config, write_insurance_config},
+    rental_pass::{read_rental_pass_contract, write_rental_pass_contract},
+    car_access::{read_access_grants, remove_access_grants, write_access_grants},
+    dispute::{read_arbiter, write_arbiter, has_damage_claim as storage_has_damage_claim, read_damage_claim, write_damage_claim},
+    blocklist::{read_block, write_block, remove_block, read_appeal, write_appeal},
+    kyc::{read_kyc_required, write_kyc_required, is_verifier as storage_is_verifier, write_verifier, read_attestation, write_attestation, remove_attestation, read_required_licence, write_required_licence},
+    zone::{has_zone, read_zone, write_zone, read_car_location, remove_car_location, read_relocation_fee, write_relocation_fee, remove_relocation_fee as storage_remove_relocation_fee},
+    car_details::{read_car_details, write_car_details, remove_car_details, read_vin_owner, write_vin_owner, remove_vin_owner},
+    car_owners::{add_car_owner, remove_car_owner, get_car_owners, get_car_owners_count, add_owner, remove_owner},
+    payout::{get_payout_destination as storage_get_payout_destination, write_payout_destination, remove_payout_destination},
+    car_token::{read_car_token, read_token_car},
+    beneficiary::{read_car_beneficiaries, write_car_beneficiaries, remove_car_beneficiaries, read_beneficiary_balance},
+    settlement::{
+        read_streaming_settlement, write_streaming_settlement,
+        read_early_return_policy, write_early_return_policy, remove_early_return_policy,
+    },
+    price_rules::{read_price_rules, write_price_rules, remove_price_rules},
+    promo_code::{has_promo_code, read_promo_code, write_promo_code},
+    rating::{read_renter_rating, read_owner_rating, read_car_rating, add_renter_score, add_owner_score, add_car_score, remove_car_rating},
+    renter_rules::{read_renter_rules, write_renter_rules, remove_renter_rules},
+    rental_history::{write_rental_record, add_active_rental, remove_active_rental, archive_rental, read_active_rentals, read_rental_history, read_rental_history_count},
+    loyalty::{read_loyalty_config, write_loyalty_config, read_loyalty_account},
+    referral::{read_referral_config, write_referral_config, read_referrer, write_referrer, read_referral_account, write_referral_account},
 };
+use crate::storage::types::appeal_status::AppealStatus;
+use crate::storage::types::block_reason::BlockReason;
 use crate::storage::types::car_status::CarStatus;
+use crate::storage::types::claim_status::ClaimStatus;
+use crate::storage::types::errors::RentACarError;
+use crate::storage::types::licence_class::LicenceClass;
+use crate::storage::types::owner_registry::OwnerRegistry;
+use crate::storage::types::reputation_tier::ReputationTier;
+use crate::storage::types::discount::Discount;
+use crate::storage::types::early_return::EarlyReturnPolicy;
+use crate::storage::structs::beneficiary::Beneficiary;
 use crate::storage::structs::car::Car;
-use crate::storage::structs::rental::Rental;
+use crate::storage::structs::rental_record::RentalRecord;
+use crate::storage::structs::attestation::Attestation;
+use crate::storage::structs::damage_claim::DamageClaim;
+use crate::storage::structs::insurance::InsuranceConfig;
+use crate::storage::structs::access_grant::AccessGrant;
+use crate::storage::types::access_role::AccessRole;
+use crate::storage::structs::rating::RatingSummary;
+use crate::storage::structs::renter_rules::RenterRules;
+use crate::storage::structs::block::{Appeal, BlockRecord};
+use crate::storage::structs::car_details::CarDetails;
+use crate::storage::structs::zone::{CarLocation, Zone};
+use crate::storage::structs::car_filter::{CarFilter, CarPage};
+use crate::storage::structs::loyalty::{LoyaltyAccount, LoyaltyConfig};
+use crate::storage::structs::price_rules::PriceRules;
+use crate::storage::structs::promo_code::PromoCode;
+use crate::storage::structs::referral::{ReferralAccount, ReferralConfig};
+use crate::storage::structs::rental_options::RentalOptions;
+use crate::storage::structs::rental::{Rental, RentalExtension};
+use crate::events::payout::PayoutDestinationSet;
+use crate::events::dispute::DamageClaimOpened;
+use crate::events::rating::{OwnerReview, RenterReview};
+use crate::methods::payment::{calculate_commission, collect_payment};
+use crate::methods::pricing::{duration_seconds, rental_price};
+use crate::methods::promo::redeem_promo_code;
+use crate::methods::referral::credit_referral_rewards;
+use crate::methods::payout::{payout, beneficiary_payout, transfer_to_owner};
+use crate::methods::discovery::list_cars as discover_cars;
+use crate::methods::blocklist::{validate_not_blocked, payouts_frozen, validate_payouts_not_frozen};
+use crate::methods::car_token::{
+    burn_car_token, car_holder, mint_car_token, require_car_holder, settle_car_transfer, TOKEN_NAME, TOKEN_SYMBOL,
+};
+use crate::methods::access::has_access;
+use crate::methods::drivers::validate_additional_driver;
+use crate::methods::rental_pass::{burn_pass, extend_pass, issue_pass};
+use crate::methods::insurance::{pay_premium, quote_coverage};
+use crate::methods::dispute::{held_deposit_record, settle_deposit, CLAIM_WINDOW_SECONDS, RESPONSE_WINDOW_SECONDS};
+use crate::methods::early_return::early_return_refund;
+use crate::methods::reputation::{reputation_tier, required_deposit};
+use crate::methods::rating::rateable_record;
+use crate::methods::kyc::validate_verified_driver;
+use crate::methods::zone::{move_car_to_zone, one_way_fee};
+use crate::methods::loyalty::{award_loyalty_points, redeem_loyalty_points};
+use crate::methods::refund::{refund_renter, commission_share};
+use crate::methods::revenue::split_revenue;
+use crate::methods::streaming::accrued_amount;
 use crate::methods::token::token::token_transfer;
 use crate::methods::validation::{
-    validate_positive_amount, validate_price, validate_rental_days,
-    validate_payment_amount, validate_withdrawal_amount, validate_not_self_rental,
+    validate_price, validate_rental_duration, validate_extension_duration, validate_duration_pricing,
+    validate_payment_amount, validate_not_self_rental, validate_beneficiaries,
+    validate_early_return_policy, validate_price_rules, validate_discount, validate_car_details,
+    validate_score, validate_renter_rules, validate_positive_amount, validate_insurance_config, validate_access_window, validate_pickup_point, MAX_ACCESS_GRANTS, MAX_PAGE_SIZE,
 };
 
 #[contract]
@@ -45,12 +125,19 @@ impl RentACarContractTrait for RentACarContract {
         admin.require_auth();
         
         validate_price(price_per_day).unwrap_or_else(|e| panic!("Invalid price: {:?}", e));
+        validate_not_blocked(env, &owner).unwrap_or_else(|e| panic!("{:?}", e));
         
         // Validate passengers is positive
         if passengers == 0 {
             panic!("Passengers must be greater than 0");
         }
         
+        // Re-adding a car may change its brand
+        if let Ok(previous) = read_car(env, &owner) {
+            remove_owner(env, &OwnerRegistry::Brand(previous.brand), &owner);
+        }
+        add_owner(env, &OwnerRegistry::Brand(brand.clone()), &owner);
+
         let car = Car {
             brand,
             model,
@@ -61,12 +148,21 @@ impl RentACarContractTrait for RentACarContract {
             car_status: CarStatus::Available,
             available_to_withdraw: 0,
             commission_percentage,
+            price_per_hour: 0,
+            min_duration_hours: 0,
+            max_duration_hours: 0,
         };
 
         write_car(env, &owner, &car);
         
         // Register owner in the car owners list
         add_car_owner(env, &owner);
+
+        // The ownership token carries the listing and its payouts from here on; re-adding a car
+        // keeps the token it already has, wherever it is held
+        if read_car_token(env, &owner).is_err() {
+            mint_car_token(env, &owner);
+        }
     }
 
     fn get_car_status(env: &Env, owner: Address) -> CarStatus {
@@ -74,12 +170,14 @@ impl RentACarContractTrait for RentACarContract {
         car.car_status
     }
 
-    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128) {
+    fn rental(env: &Env, renter: Address, owner: Address, duration_hours: u32, amount: i128, options: RentalOptions) {
         renter.require_auth();
         
         // Validate input
-        validate_rental_days(total_days_to_rent).unwrap_or_else(|e| panic!("Invalid days: {:?}", e));
-        validate_not_self_rental(&renter, &owner).unwrap_or_else(|e| panic!("{:?}", e));
+        let holder = car_holder(env, &owner);
+        validate_not_self_rental(&renter, &holder).unwrap_or_else(|e| panic!("{:?}", e));
+        validate_not_blocked(env, &renter).unwrap_or_else(|e| panic!("{:?}", e));
+        validate_not_blocked(env, &holder).unwrap_or_else(|e| panic!("{:?}", e));
         
         // Check if car exists
         let mut car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
@@ -89,8 +187,31 @@ impl RentACarContractTrait for RentACarContract {
             panic!("Car is not available");
         }
         
+        validate_rental_duration(&car, duration_hours)
+            .unwrap_or_else(|e| panic!("Invalid duration: {:?}", e));
+
+        // Calculate timestamps
+        let start_time: u64 = env.ledger().timestamp();
+        let end_time = start_time + duration_seconds(duration_hours);
+
+        validate_verified_driver(env, &renter, &owner, end_time).unwrap_or_else(|e| panic!("{:?}", e));
+
+        // Owners can require a track record and scale the deposit by the renter's reputation
+        let deposit = required_deposit(env, &renter, &owner).unwrap_or_else(|e| panic!("{:?}", e));
+
+        let price = rental_price(&car, &read_price_rules(env, &owner), start_time, duration_hours)
+            .unwrap_or_else(|e| panic!("{:?}", e));
+
+        // Promo codes and loyalty points discount the base price, the owner receives the discounted amount
+        let promo_discount = match &options.promo_code {
+            Some(code) => redeem_promo_code(env, code, &renter, &owner, price),
+            None => 0,
+        };
+        let discount = promo_discount
+            + redeem_loyalty_points(env, &renter, options.redeem_points, price - promo_discount);
+
         // Validate payment amount matches price (base amount without commission)
-        validate_payment_amount(amount, car.price_per_day, total_days_to_rent)
+        validate_payment_amount(amount, price - discount)
             .unwrap_or_else(|e| panic!("{:?}", e));
         
         // Check if rental already exists (prevent double-rental)
@@ -99,56 +220,190 @@ impl RentACarContractTrait for RentACarContract {
         }
 
         // Calculate commission based on car's commission_percentage
-        // commission_percentage is in basis points (500 = 5%)
-        let commission = (amount
-            .checked_mul(car.commission_percentage)
-            .unwrap_or_else(|| panic!("Commission calculation overflow")))
-            .checked_div(10000_i128)
-            .unwrap_or_else(|| panic!("Division by zero"));
-        
-        // Calculate total amount (deposit + commission)
-        let total_amount = amount
-            .checked_add(commission)
-            .unwrap_or_else(|| panic!("Amount overflow when adding commission"));
-
-        // Calculate timestamps
-        let start_time: u64 = env.ledger().timestamp();
-        // Calculate end time (total_days_to_rent in seconds)
-        let seconds_per_day: u64 = 86400;
-        let end_time = start_time + (total_days_to_rent as u64 * seconds_per_day);
+        let commission = calculate_commission(amount, car.commission_percentage);
 
-        car.car_status = CarStatus::Rented;
-        // Only deposit amount goes to owner's available balance
+        // One-way rentals pay the relocation fee on top, it goes to the owner without commission
+        let relocation_fee = one_way_fee(env, &owner, options.return_zone);
         car.available_to_withdraw = car.available_to_withdraw
-            .checked_add(amount)
+            .checked_add(relocation_fee)
             .unwrap_or_else(|| panic!("Balance overflow"));
 
-        let rental = Rental {
-            total_days_to_rent,
+        // Proof of the rental for parking, tolls and locks
+        let (pass_contract, pass_id) = issue_pass(env, &renter, &owner, start_time, end_time);
+
+        // Optional damage coverage, priced on the base amount
+        let (premium, coverage, insurance_pool) = quote_coverage(env, amount, options.insured);
+
+        car.car_status = CarStatus::Rented;
+        // Streaming rentals credit the owner as time passes instead of up front
+        let streaming = read_streaming_settlement(env, &owner);
+        if !streaming {
+            // Only deposit amount goes to owner's available balance
+            car.available_to_withdraw = car.available_to_withdraw
+                .checked_add(amount)
+                .unwrap_or_else(|| panic!("Balance overflow"));
+        }
+
+        let mut rental = Rental {
+            holder: holder.clone(),
+            duration_hours,
             amount,
             start_time,
             end_time,
+            commission,
+            referral_rewards: 0,
+            streaming,
+            claimed: 0,
+            discount,
+            points_redeemed: options.redeem_points.max(0),
+            return_zone: options.return_zone,
+            relocation_fee,
+            deposit,
+            premium,
+            coverage,
+            insurance_pool: insurance_pool.clone(),
+            pass_contract,
+            pass_id,
+            extensions: Vec::new(env),
+            additional_drivers: Vec::new(env),
+            driver_fees: 0,
         };
 
-        // Update contract balance with total amount (deposit + commission)
-        let mut contract_balance = read_contract_balance(&env);
-        contract_balance = contract_balance
-            .checked_add(total_amount)
-            .unwrap_or_else(|| panic!("Contract balance overflow"));
-        
-        // Accumulate commission for admin
-        let mut admin_commission_balance = read_admin_commission_balance(env);
-        admin_commission_balance = admin_commission_balance
+        collect_payment(env, &renter, amount + relocation_fee + deposit, commission);
+        rental.referral_rewards = credit_referral_rewards(env, &renter, &holder, commission);
+
+        write_car(env, &owner, &car);
+        write_rental(env, &renter, &owner, &rental);
+        write_car_renter(env, &owner, &renter);
+        add_active_rental(env, &renter, &owner);
+
+        pay_premium(env, &insurance_pool, &renter, premium, coverage);
+    }
+
+    fn extend_rental(env: &Env, renter: Address, owner: Address, extra_hours: u32, amount: i128) {
+        renter.require_auth();
+        validate_not_blocked(env, &renter).unwrap_or_else(|e| panic!("{:?}", e));
+
+        let mut rental = read_rental(env, &renter, &owner).unwrap_or_else(|_| panic!("Rental not found"));
+        let mut car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+
+        if car.car_status != CarStatus::Rented {
+            panic!("Car is not currently rented");
+        }
+
+        // Cars are only booked through `rental`, so nothing else can be scheduled after
+        // `end_time` while the car is out; an expired rental has to be returned instead
+        if env.ledger().timestamp() >= rental.end_time {
+            panic!("Rental period has already ended");
+        }
+
+        // The extension is priced on its own, the car's limits apply to the extended total
+        validate_extension_duration(&car, rental.duration_hours, extra_hours)
+            .unwrap_or_else(|e| panic!("Invalid duration: {:?}", e));
+
+        // The renter's and additional drivers' licences have to stay valid until the new end
+        let extension_end = rental.end_time + duration_seconds(extra_hours);
+        validate_verified_driver(env, &renter, &owner, extension_end).unwrap_or_else(|e| panic!("{:?}", e));
+        for driver in rental.additional_drivers.iter() {
+            validate_verified_driver(env, &driver, &owner, extension_end).unwrap_or_else(|e| panic!("{:?}", e));
+        }
+
+        // Extension days are priced from the current end_time on
+        let expected_amount = rental_price(&car, &read_price_rules(env, &owner), rental.end_time, extra_hours)
+            .unwrap_or_else(|e| panic!("{:?}", e));
+        validate_payment_amount(amount, expected_amount)
+            .unwrap_or_else(|e| panic!("{:?}", e));
+
+        let commission = calculate_commission(amount, car.commission_percentage);
+
+        // Streaming rentals accrue the extension over its own window at its own price
+        rental.extensions.push_back(RentalExtension { start_time: rental.end_time, end_time: extension_end, amount });
+        rental.end_time = extension_end;
+        rental.duration_hours += extra_hours;
+        extend_pass(env, &rental);
+        rental.amount = rental.amount
+            .checked_add(amount)
+            .unwrap_or_else(|| panic!("Rental amount overflow"));
+        rental.commission = rental.commission
             .checked_add(commission)
-            .unwrap_or_else(|| panic!("Admin commission balance overflow"));
-        
-        write_admin_commission_balance(env, &admin_commission_balance);
-        write_contract_balance(&env, &contract_balance);
+            .unwrap_or_else(|| panic!("Rental commission overflow"));
+
+        // Streaming rentals keep accruing over the longer window instead
+        if !rental.streaming {
+            car.available_to_withdraw = car.available_to_withdraw
+                .checked_add(amount)
+                .unwrap_or_else(|| panic!("Balance overflow"));
+            write_car(env, &owner, &car);
+        }
+
+        collect_payment(env, &renter, amount, commission);
+        rental.referral_rewards += credit_referral_rewards(env, &renter, &rental.holder, commission);
+        write_rental(env, &renter, &owner, &rental);
+    }
+
+    fn add_rental_driver(env: &Env, renter: Address, owner: Address, driver: Address) {
+        renter.require_auth();
+        validate_not_blocked(env, &renter).unwrap_or_else(|e| panic!("{:?}", e));
+
+        let mut rental = read_rental(env, &renter, &owner).unwrap_or_else(|_| panic!("Rental not found"));
+        let mut car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+
+        if car.car_status != CarStatus::Rented {
+            panic!("Car is not currently rented");
+        }
+        if env.ledger().timestamp() >= rental.end_time {
+            panic!("Rental period has already ended");
+        }
+
+        validate_additional_driver(env, &renter, &owner, &rental, &driver)
+            .unwrap_or_else(|e| panic!("{:?}", e));
+
+        // The per-driver fee goes to the owner without commission, like the relocation fee
+        let fee = read_driver_fee(env, &owner);
+        car.available_to_withdraw = car.available_to_withdraw
+            .checked_add(fee)
+            .unwrap_or_else(|| panic!("Balance overflow"));
+        rental.driver_fees = rental.driver_fees
+            .checked_add(fee)
+            .unwrap_or_else(|| panic!("Driver fees overflow"));
+        rental.additional_drivers.push_back(driver);
+
         write_car(env, &owner, &car);
         write_rental(env, &renter, &owner, &rental);
 
-        // Transfer total amount (deposit + commission) from renter to contract
-        token_transfer(&env, &renter, &env.current_contract_address(), &total_amount);
+        if fee > 0 {
+            collect_payment(env, &renter, fee, 0);
+        }
+    }
+
+    fn remove_rental_driver(env: &Env, renter: Address, owner: Address, driver: Address) {
+        renter.require_auth();
+
+        let mut rental = read_rental(env, &renter, &owner).unwrap_or_else(|_| panic!("Rental not found"));
+
+        // Fees already paid for the driver are not refunded
+        let index = rental.additional_drivers.first_index_of(&driver)
+            .unwrap_or_else(|| panic!("Driver not found"));
+        rental.additional_drivers.remove(index);
+        write_rental(env, &renter, &owner, &rental);
+    }
+
+    fn set_additional_driver_fee(env: &Env, owner: Address, fee: i128) {
+        require_car_holder(env, &owner);
+
+        if !storage_has_car(env, &owner) {
+            panic!("Car not found");
+        }
+        if fee < 0 {
+            panic!("{:?}", RentACarError::InvalidAmount);
+        }
+
+        // Drivers already on a rental keep the fee they were charged
+        write_driver_fee(env, &owner, fee);
+    }
+
+    fn get_additional_driver_fee(env: &Env, owner: Address) -> i128 {
+        read_driver_fee(env, &owner)
     }
 
     fn remove_car(env: &Env, owner: Address) {
@@ -162,134 +417,1140 @@ impl RentACarContractTrait for RentACarContract {
             panic!("Cannot remove car that is currently rented");
         }
         
+        burn_car_token(env, &owner);
+        remove_access_grants(env, &owner);
         remove_car_storage(env, &owner);
+        remove_car_beneficiaries(env, &owner);
+        remove_early_return_policy(env, &owner);
+        remove_payout_destination(env, &owner);
+        write_streaming_settlement(env, &owner, false);
+        remove_price_rules(env, &owner);
+        if let Ok(details) = read_car_details(env, &owner) {
+            remove_vin_owner(env, &details.vin);
+            remove_car_details(env, &owner);
+        }
+        write_required_licence(env, &owner, &None);
+        remove_car_rating(env, &owner);
+        remove_renter_rules(env, &owner);
+        remove_driver_fee(env, &owner);
+        if let Ok(location) = read_car_location(env, &owner) {
+            remove_owner(env, &OwnerRegistry::Zone(location.zone_id), &owner);
+            remove_car_location(env, &owner);
+        }
         
-        // Remove owner from the car owners list
+        // Remove owner from the car owners list and the brand index
         remove_car_owner(env, &owner);
+        remove_owner(env, &OwnerRegistry::Brand(car.brand), &owner);
     }
 
     fn payout_owner(env: &Env, owner: Address, amount: i128) {
-        owner.require_auth();
-    
-        let mut car = read_car(&env, &owner).unwrap_or_else(|_| panic!("Car not found"));
-        
-        // 🚗 Retiro de owners restringido: Solo permitir retiro cuando el auto esté devuelto
-        if car.car_status != CarStatus::Available {
-            panic!("Car must be returned before owner can withdraw funds");
+        require_car_holder(env, &owner);
+
+        payout(env, &owner, amount);
+    }
+
+    fn payout_all(env: &Env, owner: Address) -> i128 {
+        require_car_holder(env, &owner);
+
+        let car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+        let amount = car.available_to_withdraw;
+        if amount <= 0 {
+            panic!("No funds available to withdraw");
         }
-        
-        // Validate withdrawal amount doesn't exceed available
-        validate_withdrawal_amount(amount, car.available_to_withdraw)
-            .unwrap_or_else(|e| panic!("{:?}", e));
-        
-        let mut contract_balance = read_contract_balance(&env);
 
-        car.available_to_withdraw = car.available_to_withdraw
-            .checked_sub(amount)
-            .unwrap_or_else(|| panic!("Underflow in available_to_withdraw"));
-        
-        contract_balance = contract_balance
-            .checked_sub(amount)
-            .unwrap_or_else(|| panic!("Underflow in contract balance"));
+        payout(env, &owner, amount);
+        amount
+    }
+
+    fn batch_payout(env: &Env, owners: Vec<Address>) -> i128 {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
 
-        write_car(&env, &owner, &car);
-        write_contract_balance(&env, &contract_balance);
+        // Owners without a car, whose car is out, whose payouts are frozen or who have nothing
+        // to withdraw are skipped so a single entry cannot block the whole batch
+        let mut total_paid: i128 = 0;
+        for owner in owners.iter() {
+            let Ok(car) = read_car(env, &owner) else {
+                continue;
+            };
+            if car.car_status == CarStatus::Rented || car.available_to_withdraw <= 0 || payouts_frozen(env, &car_holder(env, &owner)) {
+                continue;
+            }
 
-        token_transfer(&env, &env.current_contract_address(), &owner, &amount);
+            payout(env, &owner, car.available_to_withdraw);
+            total_paid = total_paid
+                .checked_add(car.available_to_withdraw)
+                .unwrap_or_else(|| panic!("Batch payout overflow"));
+        }
+        total_paid
     }
 
-    fn get_car(env: &Env, owner: Address) -> Car {
-        read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"))
+    fn set_payout_destination(env: &Env, owner: Address, destination: Address) {
+        let holder = require_car_holder(env, &owner);
+
+        // Setting the holder itself clears any previously registered destination
+        if destination == holder {
+            remove_payout_destination(env, &owner);
+        } else {
+            write_payout_destination(env, &owner, &destination);
+        }
+
+        PayoutDestinationSet { owner, destination }.publish(env);
     }
 
-    fn has_car(env: &Env, owner: Address) -> bool {
-        storage_has_car(env, &owner)
+    fn get_payout_destination(env: &Env, owner: Address) -> Address {
+        storage_get_payout_destination(env, &owner).unwrap_or_else(|| car_holder(env, &owner))
     }
 
-    fn get_rental(env: &Env, renter: Address, owner: Address) -> Rental {
-        read_rental(env, &renter, &owner).unwrap_or_else(|_| panic!("Rental not found"))
+    fn set_car_beneficiaries(env: &Env, owner: Address, beneficiaries: Vec<Beneficiary>) {
+        require_car_holder(env, &owner);
+
+        let car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+
+        // Shares can't change while a rental is in flight, its revenue is split at completion
+        if car.car_status == CarStatus::Rented {
+            panic!("Cannot change beneficiaries while car is rented");
+        }
+
+        // An empty list turns the car back into a single-owner car
+        if beneficiaries.is_empty() {
+            remove_car_beneficiaries(env, &owner);
+            return;
+        }
+
+        validate_beneficiaries(&beneficiaries).unwrap_or_else(|e| panic!("{:?}", e));
+        write_car_beneficiaries(env, &owner, &beneficiaries);
     }
 
-    fn has_rental(env: &Env, renter: Address, owner: Address) -> bool {
-        storage_has_rental(env, &renter, &owner)
+    fn get_car_beneficiaries(env: &Env, owner: Address) -> Vec<Beneficiary> {
+        read_car_beneficiaries(env, &owner)
     }
 
-    fn get_available_to_withdraw(env: &Env, owner: Address) -> i128 {
-        let car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
-        car.available_to_withdraw
+    fn get_beneficiary_balance(env: &Env, owner: Address, beneficiary: Address) -> i128 {
+        read_beneficiary_balance(env, &owner, &beneficiary)
     }
 
-    fn end_rental(env: &Env, renter: Address, owner: Address) {
-        renter.require_auth();
-        
-        let _rental = read_rental(env, &renter, &owner).unwrap_or_else(|_| panic!("Rental not found"));
-        
-        // Verify rental exists (hasn't been ended)
-        let mut car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
-        
-        if car.car_status != CarStatus::Rented {
-            panic!("Car is not currently rented");
+    fn withdraw_beneficiary_share(env: &Env, beneficiary: Address, owner: Address) -> i128 {
+        beneficiary.require_auth();
+
+        beneficiary_payout(env, &owner, &beneficiary)
+    }
+
+    fn set_streaming_settlement(env: &Env, owner: Address, enabled: bool) {
+        require_car_holder(env, &owner);
+
+        if !storage_has_car(env, &owner) {
+            panic!("Car not found");
         }
-        
-        // Mark car as available
-        car.car_status = CarStatus::Available;
+
+        // Only affects rentals started afterwards, each rental keeps the mode it started with
+        write_streaming_settlement(env, &owner, enabled);
+    }
+
+    fn set_car_pricing(env: &Env, owner: Address, price_per_hour: i128, min_duration_hours: u32, max_duration_hours: u32) {
+        require_car_holder(env, &owner);
+
+        let mut car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+
+        validate_duration_pricing(price_per_hour, min_duration_hours, max_duration_hours)
+            .unwrap_or_else(|e| panic!("{:?}", e));
+
+        car.price_per_hour = price_per_hour;
+        car.min_duration_hours = min_duration_hours;
+        car.max_duration_hours = max_duration_hours;
         write_car(env, &owner, &car);
-        
-        // Remove rental record
-        remove_rental(env, &renter, &owner);
     }
 
-    // 🧾 Comisión del administrador
-    fn set_commission(env: &Env, commission: i128) {
-        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
-        admin.require_auth();
-        
-        // Validate commission is non-negative (0 means no commission)
-        if commission < 0 {
-            panic!("Commission cannot be negative");
+    fn set_price_rules(env: &Env, owner: Address, rules: PriceRules) {
+        require_car_holder(env, &owner);
+
+        if !storage_has_car(env, &owner) {
+            panic!("Car not found");
         }
-        
-        write_commission(env, &commission);
+
+        // Rentals already paid keep their price, rules only apply to new rentals and extensions
+        validate_price_rules(&rules).unwrap_or_else(|e| panic!("{:?}", e));
+        write_price_rules(env, &owner, &rules);
     }
 
-    fn get_commission(env: &Env) -> i128 {
-        read_commission(env)
+    fn get_price_rules(env: &Env, owner: Address) -> PriceRules {
+        read_price_rules(env, &owner)
     }
 
-    // 💸 Retiro de fondos del administrador
-    fn withdraw_admin_commissions(env: &Env) {
-        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
-        admin.require_auth();
-        
-        let mut admin_commission_balance = read_admin_commission_balance(env);
-        
-        if admin_commission_balance <= 0 {
-            panic!("No commissions available to withdraw");
+    fn rate_as_renter(env: &Env, renter: Address, rental_index: u32, car_score: u32, owner_score: u32, review_hash: BytesN<32>) {
+        renter.require_auth();
+
+        validate_score(car_score).unwrap_or_else(|e| panic!("{:?}", e));
+        validate_score(owner_score).unwrap_or_else(|e| panic!("{:?}", e));
+
+        let mut record = rateable_record(env, &renter, rental_index).unwrap_or_else(|e| panic!("{:?}", e));
+        if record.renter_rated {
+            panic!("{:?}", RentACarError::AlreadyRated);
         }
-        
-        let amount_to_withdraw = admin_commission_balance;
-        
-        // Reset admin commission balance
-        admin_commission_balance = 0;
-        write_admin_commission_balance(env, &admin_commission_balance);
-        
-        // Update contract balance
-        let mut contract_balance = read_contract_balance(&env);
-        contract_balance = contract_balance
-            .checked_sub(amount_to_withdraw)
-            .unwrap_or_else(|| panic!("Underflow in contract balance"));
-        write_contract_balance(&env, &contract_balance);
-        
-        // Transfer commissions to admin
-        token_transfer(&env, &env.current_contract_address(), &admin, &amount_to_withdraw);
+        record.renter_rated = true;
+        write_rental_record(env, &renter, rental_index, &record);
+
+        // The car may have been delisted since, its owner can still be rated
+        if storage_has_car(env, &record.owner) {
+            add_car_score(env, &record.owner, car_score);
+        }
+        add_owner_score(env, &record.owner, owner_score);
+
+        RenterReview {
+            renter,
+            owner: record.owner,
+            rental_index,
+            car_score,
+            owner_score,
+            review_hash,
+        }
+        .publish(env);
     }
 
-    fn get_admin_commission_balance(env: &Env) -> i128 {
-        read_admin_commission_balance(env)
+    fn rate_as_owner(env: &Env, owner: Address, renter: Address, rental_index: u32, score: u32, review_hash: BytesN<32>) {
+        require_car_holder(env, &owner);
+
+        validate_score(score).unwrap_or_else(|e| panic!("{:?}", e));
+
+        let mut record = rateable_record(env, &renter, rental_index).unwrap_or_else(|e| panic!("{:?}", e));
+        if record.owner != owner {
+            panic!("{:?}", RentACarError::NotOwner);
+        }
+        if record.owner_rated {
+            panic!("{:?}", RentACarError::AlreadyRated);
+        }
+        record.owner_rated = true;
+        write_rental_record(env, &renter, rental_index, &record);
+
+        add_renter_score(env, &renter, score);
+
+        OwnerReview {
+            owner,
+            renter,
+            rental_index,
+            score,
+            review_hash,
+        }
+        .publish(env);
     }
 
-    // Get all car owner addresses
-    fn get_all_car_owners(env: &Env) -> Vec<Address> {
-        get_car_owners(env)
+    fn set_renter_rules(env: &Env, owner: Address, rules: RenterRules) {
+        require_car_holder(env, &owner);
+
+        if !storage_has_car(env, &owner) {
+            panic!("Car not found");
+        }
+
+        // Rentals already running keep the deposit they were charged
+        validate_renter_rules(&rules).unwrap_or_else(|e| panic!("{:?}", e));
+        write_renter_rules(env, &owner, &rules);
+    }
+
+    fn get_renter_rules(env: &Env, owner: Address) -> RenterRules {
+        read_renter_rules(env, &owner)
+    }
+
+    fn get_reputation_tier(env: &Env, renter: Address) -> ReputationTier {
+        reputation_tier(env, &renter)
+    }
+
+    fn get_required_deposit(env: &Env, renter: Address, owner: Address) -> i128 {
+        required_deposit(env, &renter, &owner).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    fn get_renter_rating(env: &Env, renter: Address) -> RatingSummary {
+        read_renter_rating(env, &renter)
+    }
+
+    fn get_owner_rating(env: &Env, owner: Address) -> RatingSummary {
+        read_owner_rating(env, &owner)
+    }
+
+    fn get_car_rating(env: &Env, owner: Address) -> RatingSummary {
+        read_car_rating(env, &owner)
+    }
+
+    fn balance(env: &Env, account: Address) -> u32 {
+        Base::balance(env, &account)
+    }
+
+    fn owner_of(env: &Env, token_id: u32) -> Address {
+        Base::owner_of(env, token_id)
+    }
+
+    fn transfer(env: &Env, from: Address, to: Address, token_id: u32) {
+        settle_car_transfer(env, &to, token_id);
+        Base::transfer(env, &from, &to, token_id);
+    }
+
+    fn transfer_from(env: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        settle_car_transfer(env, &to, token_id);
+        Base::transfer_from(env, &spender, &from, &to, token_id);
+    }
+
+    fn approve(env: &Env, approver: Address, approved: Address, token_id: u32, live_until_ledger: u32) {
+        Base::approve(env, &approver, &approved, token_id, live_until_ledger);
+    }
+
+    fn approve_for_all(env: &Env, owner: Address, operator: Address, live_until_ledger: u32) {
+        Base::approve_for_all(env, &owner, &operator, live_until_ledger);
+    }
+
+    fn get_approved(env: &Env, token_id: u32) -> Option<Address> {
+        Base::get_approved(env, token_id)
+    }
+
+    fn is_approved_for_all(env: &Env, owner: Address, operator: Address) -> bool {
+        Base::is_approved_for_all(env, &owner, &operator)
+    }
+
+    fn name(env: &Env) -> String {
+        String::from_str(env, TOKEN_NAME)
+    }
+
+    fn symbol(env: &Env) -> String {
+        String::from_str(env, TOKEN_SYMBOL)
+    }
+
+    fn token_uri(env: &Env, token_id: u32) -> String {
+        // Points at the car's photos, empty until the owner sets its details
+        let owner = read_token_car(env, token_id).unwrap_or_else(|e| panic!("{:?}", e));
+        read_car_details(env, &owner)
+            .map(|details| details.photo_uri)
+            .unwrap_or_else(|_| String::from_str(env, ""))
+    }
+
+    fn get_car_token(env: &Env, owner: Address) -> u32 {
+        read_car_token(env, &owner).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    fn get_token_car(env: &Env, token_id: u32) -> Address {
+        read_token_car(env, token_id).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    fn grant_car_access(env: &Env, owner: Address, grantee: Address, role: AccessRole, valid_from: u64, valid_until: u64) {
+        let holder = require_car_holder(env, &owner);
+
+        validate_access_window(valid_from, valid_until, env.ledger().timestamp())
+            .unwrap_or_else(|e| panic!("{:?}", e));
+        if grantee == holder {
+            panic!("{:?}", RentACarError::InvalidAccessGrant);
+        }
+
+        // A new grant for the same address replaces the previous one
+        let mut grants = read_access_grants(env, &owner);
+        if let Some(index) = grants.iter().position(|grant| grant.grantee == grantee) {
+            grants.remove(index as u32);
+        }
+        if grants.len() >= MAX_ACCESS_GRANTS {
+            panic!("{:?}", RentACarError::InvalidAccessGrant);
+        }
+
+        grants.push_back(AccessGrant { grantee, role, valid_from, valid_until });
+        write_access_grants(env, &owner, &grants);
+    }
+
+    fn revoke_car_access(env: &Env, owner: Address, grantee: Address) {
+        require_car_holder(env, &owner);
+
+        let mut grants = read_access_grants(env, &owner);
+        let index = grants.iter().position(|grant| grant.grantee == grantee)
+            .unwrap_or_else(|| panic!("Access grant not found"));
+        grants.remove(index as u32);
+        write_access_grants(env, &owner, &grants);
+    }
+
+    fn get_car_access_grants(env: &Env, owner: Address) -> Vec<AccessGrant> {
+        read_access_grants(env, &owner)
+    }
+
+    fn set_car_maintenance(env: &Env, owner: Address, in_maintenance: bool) {
+        require_car_holder(env, &owner);
+
+        let mut car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+        if car.car_status == CarStatus::Rented {
+            panic!("Car is currently rented");
+        }
+
+        // Cars in maintenance cannot be rented
+        car.car_status = if in_maintenance { CarStatus::Maintenance } else { CarStatus::Available };
+        write_car(env, &owner, &car);
+    }
+
+    fn can_access(env: &Env, car: Address, address: Address, timestamp: u64) -> bool {
+        has_access(env, &car, &address, timestamp)
+    }
+
+    fn set_rental_pass_contract(env: &Env, pass: Address) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        // Rentals already running keep their passes in the contract that issued them
+        write_rental_pass_contract(env, &pass);
+    }
+
+    fn get_rental_pass_contract(env: &Env) -> Option<Address> {
+        read_rental_pass_contract(env)
+    }
+
+    fn set_insurance_config(env: &Env, config: InsuranceConfig) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        // Rentals already running keep the coverage they bought, and claim it from the pool that sold it
+        validate_insurance_config(&config).unwrap_or_else(|e| panic!("{:?}", e));
+        write_insurance_config(env, &config);
+    }
+
+    fn get_insurance_config(env: &Env) -> InsuranceConfig {
+        read_insurance_config(env).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    fn set_arbiter(env: &Env, arbiter: Address) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        write_arbiter(env, &arbiter);
+    }
+
+    fn get_arbiter(env: &Env) -> Address {
+        read_arbiter(env).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    fn open_damage_claim(env: &Env, owner: Address, renter: Address, rental_index: u32, amount: i128, evidence_hash: BytesN<32>) {
+        let record = held_deposit_record(env, &renter, rental_index).unwrap_or_else(|e| panic!("{:?}", e));
+        if record.owner != owner {
+            panic!("{:?}", RentACarError::NotOwner);
+        }
+        // Claims belong to whoever held the car during the rental, even after a transfer
+        record.holder.require_auth();
+
+        let now = env.ledger().timestamp();
+        if now > record.returned_at + CLAIM_WINDOW_SECONDS {
+            panic!("{:?}", RentACarError::ClaimWindowClosed);
+        }
+        if storage_has_damage_claim(env, &renter, rental_index) {
+            panic!("{:?}", RentACarError::ClaimExists);
+        }
+        validate_positive_amount(amount).unwrap_or_else(|e| panic!("{:?}", e));
+        // Insured rentals can be claimed beyond the deposit, up to the coverage
+        if amount > record.deposit + record.coverage {
+            panic!("{:?}", RentACarError::ClaimExceedsDeposit);
+        }
+
+        write_damage_claim(env, &renter, rental_index, &DamageClaim {
+            owner: owner.clone(),
+            amount,
+            evidence_hash: evidence_hash.clone(),
+            renter_evidence_hash: BytesN::from_array(env, &[0; 32]),
+            opened_at: now,
+            status: ClaimStatus::Open,
+            awarded: 0,
+            covered: 0,
+            settled_at: 0,
+        });
+
+        DamageClaimOpened { renter, owner, rental_index, amount, evidence_hash }.publish(env);
+    }
+
+    fn accept_damage_claim(env: &Env, renter: Address, rental_index: u32) {
+        renter.require_auth();
+
+        let mut claim = read_damage_claim(env, &renter, rental_index).unwrap_or_else(|_| panic!("Damage claim not found"));
+        if claim.status != ClaimStatus::Open {
+            panic!("{:?}", RentACarError::ClaimNotPending);
+        }
+
+        claim.status = ClaimStatus::Accepted;
+        claim.awarded = claim.amount;
+        claim.settled_at = env.ledger().timestamp();
+        claim.covered = settle_deposit(env, &renter, rental_index, claim.awarded);
+        write_damage_claim(env, &renter, rental_index, &claim);
+    }
+
+    fn contest_damage_claim(env: &Env, renter: Address, rental_index: u32, evidence_hash: BytesN<32>) {
+        renter.require_auth();
+
+        let mut claim = read_damage_claim(env, &renter, rental_index).unwrap_or_else(|_| panic!("Damage claim not found"));
+        if claim.status != ClaimStatus::Open {
+            panic!("{:?}", RentACarError::ClaimNotPending);
+        }
+
+        claim.status = ClaimStatus::Contested;
+        claim.renter_evidence_hash = evidence_hash;
+        write_damage_claim(env, &renter, rental_index, &claim);
+    }
+
+    fn resolve_damage_claim(env: &Env, arbiter: Address, renter: Address, rental_index: u32, awarded: i128) {
+        arbiter.require_auth();
+
+        if read_arbiter(env).ok() != Some(arbiter) {
+            panic!("{:?}", RentACarError::NotArbiter);
+        }
+
+        let mut claim = read_damage_claim(env, &renter, rental_index).unwrap_or_else(|_| panic!("Damage claim not found"));
+        let now = env.ledger().timestamp();
+        match claim.status {
+            ClaimStatus::Contested => {}
+            // A renter who never responds cannot hold the deposit forever
+            ClaimStatus::Open if now > claim.opened_at + RESPONSE_WINDOW_SECONDS => {}
+            ClaimStatus::Open => panic!("{:?}", RentACarError::ResponseWindowOpen),
+            _ => panic!("{:?}", RentACarError::ClaimNotPending),
+        }
+        if awarded < 0 || awarded > claim.amount {
+            panic!("{:?}", RentACarError::ClaimExceedsDeposit);
+        }
+
+        claim.status = ClaimStatus::Resolved;
+        claim.awarded = awarded;
+        claim.settled_at = now;
+        claim.covered = settle_deposit(env, &renter, rental_index, awarded);
+        write_damage_claim(env, &renter, rental_index, &claim);
+    }
+
+    fn get_damage_claim(env: &Env, renter: Address, rental_index: u32) -> DamageClaim {
+        read_damage_claim(env, &renter, rental_index).unwrap_or_else(|_| panic!("Damage claim not found"))
+    }
+
+    fn release_deposit(env: &Env, renter: Address, rental_index: u32) {
+        let record = held_deposit_record(env, &renter, rental_index).unwrap_or_else(|e| panic!("{:?}", e));
+
+        // Once a claim is opened the deposit only moves through the claim
+        if storage_has_damage_claim(env, &renter, rental_index) {
+            panic!("{:?}", RentACarError::ClaimExists);
+        }
+        if env.ledger().timestamp() <= record.returned_at + CLAIM_WINDOW_SECONDS {
+            panic!("{:?}", RentACarError::ClaimWindowOpen);
+        }
+
+        settle_deposit(env, &renter, rental_index, 0);
+    }
+
+    fn block_address(env: &Env, address: Address, reason: BlockReason, freeze_payouts: bool) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        write_block(env, &address, &BlockRecord {
+            reason,
+            blocked_at: env.ledger().timestamp(),
+            freeze_payouts,
+        });
+    }
+
+    fn unblock_address(env: &Env, address: Address) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        read_block(env, &address).unwrap_or_else(|_| panic!("{:?}", RentACarError::NotBlocked));
+        remove_block(env, &address);
+
+        // Lifting the block settles an appeal still waiting for review
+        if let Ok(mut appeal) = read_appeal(env, &address) {
+            if appeal.status == AppealStatus::Pending {
+                appeal.status = AppealStatus::Accepted;
+                appeal.resolved_at = env.ledger().timestamp();
+                write_appeal(env, &address, &appeal);
+            }
+        }
+    }
+
+    fn get_block(env: &Env, address: Address) -> BlockRecord {
+        read_block(env, &address).unwrap_or_else(|_| panic!("{:?}", RentACarError::NotBlocked))
+    }
+
+    fn is_blocked(env: &Env, address: Address) -> bool {
+        validate_not_blocked(env, &address).is_err()
+    }
+
+    fn file_appeal(env: &Env, address: Address, evidence_hash: BytesN<32>) {
+        address.require_auth();
+
+        read_block(env, &address).unwrap_or_else(|_| panic!("{:?}", RentACarError::NotBlocked));
+        if let Ok(appeal) = read_appeal(env, &address) {
+            if appeal.status == AppealStatus::Pending {
+                panic!("{:?}", RentACarError::AppealPending);
+            }
+        }
+
+        write_appeal(env, &address, &Appeal {
+            evidence_hash,
+            filed_at: env.ledger().timestamp(),
+            status: AppealStatus::Pending,
+            resolved_at: 0,
+        });
+    }
+
+    fn resolve_appeal(env: &Env, address: Address, accept: bool) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        let mut appeal = read_appeal(env, &address)
+            .unwrap_or_else(|_| panic!("{:?}", RentACarError::NoPendingAppeal));
+        if appeal.status != AppealStatus::Pending {
+            panic!("{:?}", RentACarError::NoPendingAppeal);
+        }
+
+        // Accepting an appeal lifts the block, rejecting it leaves the block in place
+        if accept {
+            appeal.status = AppealStatus::Accepted;
+            remove_block(env, &address);
+        } else {
+            appeal.status = AppealStatus::Rejected;
+        }
+        appeal.resolved_at = env.ledger().timestamp();
+        write_appeal(env, &address, &appeal);
+    }
+
+    fn get_appeal(env: &Env, address: Address) -> Appeal {
+        read_appeal(env, &address).unwrap_or_else(|_| panic!("Appeal not found"))
+    }
+
+    fn set_kyc_required(env: &Env, required: bool) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        write_kyc_required(env, required);
+    }
+
+    fn get_kyc_required(env: &Env) -> bool {
+        read_kyc_required(env)
+    }
+
+    fn set_verifier(env: &Env, verifier: Address, approved: bool) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        write_verifier(env, &verifier, approved);
+    }
+
+    fn is_verifier(env: &Env, verifier: Address) -> bool {
+        storage_is_verifier(env, &verifier)
+    }
+
+    fn attest_licence(env: &Env, verifier: Address, driver: Address, classes: Vec<LicenceClass>, expires_at: u64) {
+        verifier.require_auth();
+
+        if !storage_is_verifier(env, &verifier) {
+            panic!("{:?}", RentACarError::NotVerifier);
+        }
+        if classes.is_empty() {
+            panic!("Attestation must cover at least one licence class");
+        }
+
+        let issued_at = env.ledger().timestamp();
+        if expires_at <= issued_at {
+            panic!("{:?}", RentACarError::AttestationExpired);
+        }
+
+        write_attestation(env, &driver, &Attestation { verifier, classes, issued_at, expires_at });
+    }
+
+    fn revoke_attestation(env: &Env, verifier: Address, driver: Address) {
+        verifier.require_auth();
+
+        if !storage_is_verifier(env, &verifier) {
+            panic!("{:?}", RentACarError::NotVerifier);
+        }
+
+        remove_attestation(env, &driver);
+    }
+
+    fn get_attestation(env: &Env, driver: Address) -> Attestation {
+        read_attestation(env, &driver).unwrap_or_else(|_| panic!("Attestation not found"))
+    }
+
+    fn set_required_licence(env: &Env, owner: Address, class: Option<LicenceClass>) {
+        require_car_holder(env, &owner);
+
+        if !storage_has_car(env, &owner) {
+            panic!("Car not found");
+        }
+
+        write_required_licence(env, &owner, &class);
+    }
+
+    fn get_required_licence(env: &Env, owner: Address) -> Option<LicenceClass> {
+        read_required_licence(env, &owner)
+    }
+
+    fn add_zone(env: &Env, zone_id: u32, name: String, pickup_point: String) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        if has_zone(env, zone_id) {
+            panic!("Zone already exists");
+        }
+        validate_pickup_point(&pickup_point).unwrap_or_else(|e| panic!("{:?}", e));
+
+        write_zone(env, zone_id, &Zone { name, pickup_point });
+    }
+
+    fn get_zone(env: &Env, zone_id: u32) -> Zone {
+        read_zone(env, zone_id).unwrap_or_else(|_| panic!("Zone not found"))
+    }
+
+    fn set_relocation_fee(env: &Env, from_zone: u32, to_zone: u32, fee: i128) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        if !has_zone(env, from_zone) || !has_zone(env, to_zone) {
+            panic!("Zone not found");
+        }
+        if from_zone == to_zone {
+            panic!("Relocation fee requires two different zones");
+        }
+        if fee < 0 {
+            panic!("Relocation fee cannot be negative");
+        }
+
+        write_relocation_fee(env, from_zone, to_zone, &fee);
+    }
+
+    fn remove_relocation_fee(env: &Env, from_zone: u32, to_zone: u32) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        storage_remove_relocation_fee(env, from_zone, to_zone);
+    }
+
+    fn get_relocation_fee(env: &Env, from_zone: u32, to_zone: u32) -> Option<i128> {
+        read_relocation_fee(env, from_zone, to_zone)
+    }
+
+    fn set_car_zone(env: &Env, owner: Address, zone_id: u32, pickup_point: String) {
+        require_car_holder(env, &owner);
+
+        let car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+        if car.car_status == CarStatus::Rented {
+            panic!("Cannot move a car while it is rented");
+        }
+        validate_pickup_point(&pickup_point).unwrap_or_else(|e| panic!("{:?}", e));
+
+        move_car_to_zone(env, &owner, zone_id, pickup_point);
+    }
+
+    fn get_car_location(env: &Env, owner: Address) -> CarLocation {
+        read_car_location(env, &owner).unwrap_or_else(|_| panic!("Car is not assigned to a zone"))
+    }
+
+    fn set_car_details(env: &Env, owner: Address, details: CarDetails) {
+        require_car_holder(env, &owner);
+
+        if !storage_has_car(env, &owner) {
+            panic!("Car not found");
+        }
+
+        validate_car_details(&details).unwrap_or_else(|e| panic!("{:?}", e));
+
+        if let Some(vin_owner) = read_vin_owner(env, &details.vin) {
+            if vin_owner != owner {
+                panic!("{:?}", RentACarError::DuplicateVin);
+            }
+        }
+
+        // Free the previous VIN if the owner corrected it
+        if let Ok(previous) = read_car_details(env, &owner) {
+            if previous.vin != details.vin {
+                remove_vin_owner(env, &previous.vin);
+            }
+        }
+
+        write_vin_owner(env, &details.vin, &owner);
+        write_car_details(env, &owner, &details);
+    }
+
+    fn get_car_details(env: &Env, owner: Address) -> CarDetails {
+        read_car_details(env, &owner).unwrap_or_else(|_| panic!("Car details not found"))
+    }
+
+    fn get_car_by_vin(env: &Env, vin: String) -> Address {
+        read_vin_owner(env, &vin).unwrap_or_else(|| panic!("VIN not found"))
+    }
+
+    fn quote_rental(env: &Env, owner: Address, start_time: u64, duration_hours: u32) -> i128 {
+        let car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+
+        validate_rental_duration(&car, duration_hours)
+            .unwrap_or_else(|e| panic!("Invalid duration: {:?}", e));
+
+        rental_price(&car, &read_price_rules(env, &owner), start_time, duration_hours)
+            .unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    fn create_promo_code(env: &Env, creator: Address, code_hash: BytesN<32>, discount: Discount, max_uses: u32, per_renter_limit: u32, expires_at: u64) {
+        // Admin codes apply to every car, owner codes only to the owner's own car and are
+        // created by its current holder
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        let car_owner = if creator == admin {
+            admin.require_auth();
+            None
+        } else if storage_has_car(env, &creator) {
+            require_car_holder(env, &creator);
+            Some(creator.clone())
+        } else {
+            panic!("Only the admin or a car owner can create promo codes");
+        };
+
+        validate_discount(&discount).unwrap_or_else(|e| panic!("{:?}", e));
+
+        if has_promo_code(env, &code_hash) {
+            panic!("Promo code already exists");
+        }
+
+        let promo = PromoCode {
+            creator,
+            car_owner,
+            discount,
+            max_uses,
+            uses: 0,
+            per_renter_limit,
+            expires_at,
+            active: true,
+        };
+        write_promo_code(env, &code_hash, &promo);
+    }
+
+    fn disable_promo_code(env: &Env, caller: Address, code_hash: BytesN<32>) {
+        let mut promo = read_promo_code(env, &code_hash).unwrap_or_else(|_| panic!("Promo code not found"));
+
+        // Owner codes are disabled by whoever holds the car now
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        if caller == admin {
+            admin.require_auth();
+        } else if caller == promo.creator {
+            require_car_holder(env, &caller);
+        } else {
+            panic!("Only the creator or the admin can disable a promo code");
+        }
+
+        promo.active = false;
+        write_promo_code(env, &code_hash, &promo);
+    }
+
+    fn get_promo_code(env: &Env, code_hash: BytesN<32>) -> PromoCode {
+        read_promo_code(env, &code_hash).unwrap_or_else(|_| panic!("Promo code not found"))
+    }
+
+    fn set_early_return_policy(env: &Env, owner: Address, policy: EarlyReturnPolicy) {
+        require_car_holder(env, &owner);
+
+        let car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+
+        // The renter agreed to the policy in force when the rental started
+        if car.car_status == CarStatus::Rented {
+            panic!("Cannot change early return policy while car is rented");
+        }
+
+        validate_early_return_policy(&policy).unwrap_or_else(|e| panic!("{:?}", e));
+        write_early_return_policy(env, &owner, &policy);
+    }
+
+    fn get_early_return_policy(env: &Env, owner: Address) -> EarlyReturnPolicy {
+        read_early_return_policy(env, &owner)
+    }
+
+    fn get_streaming_settlement(env: &Env, owner: Address) -> bool {
+        read_streaming_settlement(env, &owner)
+    }
+
+    fn get_streamed_claimable(env: &Env, renter: Address, owner: Address) -> i128 {
+        let rental = read_rental(env, &renter, &owner).unwrap_or_else(|_| panic!("Rental not found"));
+        if !rental.streaming {
+            return 0;
+        }
+        accrued_amount(&rental, env.ledger().timestamp()) - rental.claimed
+    }
+
+    fn claim_streamed(env: &Env, owner: Address, renter: Address) -> i128 {
+        require_car_holder(env, &owner);
+
+        let mut rental = read_rental(env, &renter, &owner).unwrap_or_else(|_| panic!("Rental not found"));
+        if !rental.streaming {
+            panic!("Rental is not streaming");
+        }
+
+        let claimable = accrued_amount(&rental, env.ledger().timestamp()) - rental.claimed;
+        if claimable <= 0 {
+            panic!("No funds available to withdraw");
+        }
+
+        rental.claimed += claimable;
+        write_rental(env, &renter, &owner, &rental);
+
+        // Co-owned cars settle the claim into each beneficiary's balance
+        if read_car_beneficiaries(env, &owner).is_empty() {
+            transfer_to_owner(env, &owner, claimable);
+        } else {
+            let mut car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+            car.available_to_withdraw = car.available_to_withdraw
+                .checked_add(claimable)
+                .unwrap_or_else(|| panic!("Balance overflow"));
+            split_revenue(env, &owner, &mut car, claimable);
+            write_car(env, &owner, &car);
+        }
+
+        claimable
+    }
+
+    fn get_car(env: &Env, owner: Address) -> Car {
+        read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"))
+    }
+
+    fn has_car(env: &Env, owner: Address) -> bool {
+        storage_has_car(env, &owner)
+    }
+
+    fn get_rental(env: &Env, renter: Address, owner: Address) -> Rental {
+        read_rental(env, &renter, &owner).unwrap_or_else(|_| panic!("Rental not found"))
+    }
+
+    fn has_rental(env: &Env, renter: Address, owner: Address) -> bool {
+        storage_has_rental(env, &renter, &owner)
+    }
+
+    fn get_active_rentals(env: &Env, renter: Address) -> Vec<Address> {
+        read_active_rentals(env, &renter)
+    }
+
+    fn get_rental_history(env: &Env, renter: Address, start: u32, limit: u32) -> Vec<RentalRecord> {
+        read_rental_history(env, &renter, start, limit.min(MAX_PAGE_SIZE))
+    }
+
+    fn get_rental_history_count(env: &Env, renter: Address) -> u32 {
+        read_rental_history_count(env, &renter)
+    }
+
+    fn get_available_to_withdraw(env: &Env, owner: Address) -> i128 {
+        let car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+        car.available_to_withdraw
+    }
+
+    fn end_rental(env: &Env, renter: Address, owner: Address) {
+        renter.require_auth();
+        
+        let rental = read_rental(env, &renter, &owner).unwrap_or_else(|_| panic!("Rental not found"));
+        
+        // Verify rental exists (hasn't been ended)
+        let mut car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+        
+        if car.car_status != CarStatus::Rented {
+            panic!("Car is not currently rented");
+        }
+        
+        let now = env.ledger().timestamp();
+
+        // Streaming rentals only earn what has accrued so far; the rest goes back to the renter
+        let (credit, refund, commission_refund) = if rental.streaming {
+            let accrued = accrued_amount(&rental, now);
+            let refund = rental.amount - accrued;
+            let commission_refund = refund_renter(env, &renter, &owner, refund, commission_share(&rental, refund));
+
+            let unclaimed = accrued - rental.claimed;
+            car.available_to_withdraw = car.available_to_withdraw
+                .checked_add(unclaimed)
+                .unwrap_or_else(|| panic!("Balance overflow"));
+            (unclaimed, refund, commission_refund)
+        } else {
+            // Up-front rentals refund unused days according to the car's early-return policy
+            let refund = early_return_refund(&rental, &read_early_return_policy(env, &owner), now);
+            car.available_to_withdraw = car.available_to_withdraw
+                .checked_sub(refund)
+                .unwrap_or_else(|| panic!("Underflow in available_to_withdraw"));
+            let commission_refund = refund_renter(env, &renter, &owner, refund, commission_share(&rental, refund));
+            (rental.amount - refund, refund, commission_refund)
+        };
+
+        // Settle the rental revenue among co-owners, if any; one-way and driver fees were credited up front
+        split_revenue(env, &owner, &mut car, credit + rental.relocation_fee + rental.driver_fees);
+
+        // Loyalty points are earned on what the renter actually paid
+        award_loyalty_points(env, &renter, rental.amount - refund);
+
+        // The deposit stays held until the damage claim window closes
+
+        // Mark car as available
+        car.car_status = CarStatus::Available;
+        write_car(env, &owner, &car);
+
+        // One-way rentals leave the car at the return zone's default pickup point; a car already
+        // in that zone keeps its own pickup point
+        if let Some(zone_id) = rental.return_zone {
+            let in_zone = read_car_location(env, &owner).is_ok_and(|location| location.zone_id == zone_id);
+            if !in_zone {
+                move_car_to_zone(env, &owner, zone_id, String::from_str(env, ""));
+            }
+        }
+        
+        // Archive the rental in the renter's history and remove the active record
+        archive_rental(env, &renter, &RentalRecord {
+            owner: owner.clone(),
+            holder: rental.holder.clone(),
+            start_time: rental.start_time,
+            end_time: rental.end_time,
+            returned_at: now,
+            duration_hours: rental.duration_hours,
+            amount: rental.amount,
+            commission: rental.commission,
+            discount: rental.discount,
+            refund,
+            commission_refund,
+            deposit: rental.deposit,
+            premium: rental.premium,
+            coverage: rental.coverage,
+            insurance_pool: rental.insurance_pool.clone(),
+            additional_drivers: rental.additional_drivers.clone(),
+            driver_fees: rental.driver_fees,
+            deposit_settled: rental.deposit == 0 && rental.coverage == 0,
+            renter_rated: false,
+            owner_rated: false,
+        });
+        burn_pass(env, &rental);
+        remove_active_rental(env, &renter, &owner);
+        remove_car_renter(env, &owner);
+        remove_rental(env, &renter, &owner);
+    }
+
+    // 🧾 Comisión del administrador
+    fn set_commission(env: &Env, commission: i128) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+        
+        // Validate commission is non-negative (0 means no commission)
+        if commission < 0 {
+            panic!("Commission cannot be negative");
+        }
+        
+        write_commission(env, &commission);
+    }
+
+    fn get_commission(env: &Env) -> i128 {
+        read_commission(env)
+    }
+
+    // 💸 Retiro de fondos del administrador
+    fn withdraw_admin_commissions(env: &Env) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+        
+        let mut admin_commission_balance = read_admin_commission_balance(env);
+        
+        if admin_commission_balance <= 0 {
+            panic!("No commissions available to withdraw");
+        }
+        
+        let amount_to_withdraw = admin_commission_balance;
+        
+        // Reset admin commission balance
+        admin_commission_balance = 0;
+        write_admin_commission_balance(env, &admin_commission_balance);
+        
+        // Update contract balance
+        let mut contract_balance = read_contract_balance(env);
+        contract_balance = contract_balance
+            .checked_sub(amount_to_withdraw)
+            .unwrap_or_else(|| panic!("Underflow in contract balance"));
+        write_contract_balance(env, &contract_balance);
+        
+        // Transfer commissions to admin
+        token_transfer(env, &env.current_contract_address(), &admin, &amount_to_withdraw);
+    }
+
+    fn get_admin_commission_balance(env: &Env) -> i128 {
+        read_admin_commission_balance(env)
+    }
+
+    fn set_referral_config(env: &Env, share_bps: u32, cap_per_referrer: i128) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        if share_bps > 10000 {
+            panic!("Referral share cannot exceed 10000 basis points");
+        }
+        if cap_per_referrer < 0 {
+            panic!("Referral cap cannot be negative");
+        }
+
+        write_referral_config(env, &ReferralConfig { share_bps, cap_per_referrer });
+    }
+
+    fn set_loyalty_config(env: &Env, earn_bps: u32, point_value: i128) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        if point_value < 0 {
+            panic!("Point value cannot be negative");
+        }
+
+        write_loyalty_config(env, &LoyaltyConfig { earn_bps, point_value });
+    }
+
+    fn get_loyalty_config(env: &Env) -> LoyaltyConfig {
+        read_loyalty_config(env)
+    }
+
+    fn get_loyalty_account(env: &Env, renter: Address) -> LoyaltyAccount {
+        read_loyalty_account(env, &renter)
+    }
+
+    fn get_referral_config(env: &Env) -> ReferralConfig {
+        read_referral_config(env)
+    }
+
+    fn register_referrer(env: &Env, user: Address, referrer: Address) {
+        user.require_auth();
+
+        if user == referrer {
+            panic!("Cannot refer yourself");
+        }
+        if read_referrer(env, &user).is_some() {
+            panic!("Referrer already registered");
+        }
+
+        write_referrer(env, &user, &referrer);
+    }
+
+    fn get_referrer(env: &Env, user: Address) -> Option<Address> {
+        read_referrer(env, &user)
+    }
+
+    fn get_referral_account(env: &Env, referrer: Address) -> ReferralAccount {
+        read_referral_account(env, &referrer)
+    }
+
+    fn claim_referral_rewards(env: &Env, referrer: Address) -> i128 {
+        referrer.require_auth();
+        validate_payouts_not_frozen(env, &referrer).unwrap_or_else(|e| panic!("{:?}", e));
+
+        let mut account = read_referral_account(env, &referrer);
+        let amount = account.balance;
+        if amount <= 0 {
+            panic!("No referral rewards to claim");
+        }
+
+        account.balance = 0;
+        write_referral_account(env, &referrer, &account);
+
+        let contract_balance = read_contract_balance(env)
+            .checked_sub(amount)
+            .unwrap_or_else(|| panic!("Underflow in contract balance"));
+        write_contract_balance(env, &contract_balance);
+
+        // Payout destinations belong to cars, rewards go to the referrer themselves
+        token_transfer(env, &env.current_contract_address(), &referrer, &amount);
+
+        amount
+    }
+
+    // Get all car owner addresses
+    fn get_all_car_owners(env: &Env) -> Vec<Address> {
+        get_car_owners(env)
+    }
+
+    fn get_car_owners_count(env: &Env) -> u32 {
+        get_car_owners_count(env)
+    }
+
+    fn list_cars(env: &Env, filter: CarFilter, cursor: u32, limit: u32) -> CarPage {
+        discover_cars(env, &filter, cursor, limit.min(MAX_PAGE_SIZE))
     }
 }
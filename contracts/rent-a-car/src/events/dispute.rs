@@ -0,0 +1,28 @@
+use soroban_sdk::{contractevent, Address, BytesN};
+
+/// Emitted when an owner opens a damage claim against a completed rental
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DamageClaimOpened {
+    #[topic]
+    pub renter: Address,
+    #[topic]
+    pub owner: Address,
+    pub rental_index: u32,
+    pub amount: i128,
+    pub evidence_hash: BytesN<32>,
+}
+
+/// Emitted when a held deposit is split between the owner and the renter
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositSettled {
+    #[topic]
+    pub renter: Address,
+    #[topic]
+    pub owner: Address,
+    pub rental_index: u32,
+    pub awarded: i128,
+    pub covered: i128,
+    pub returned: i128,
+}
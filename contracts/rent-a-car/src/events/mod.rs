@@ -0,0 +1,5 @@
+pub mod dispute;
+pub mod payout;
+pub mod rating;
+pub mod referral;
+pub mod rental;
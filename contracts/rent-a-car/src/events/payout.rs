@@ -0,0 +1,32 @@
+use soroban_sdk::{contractevent, Address};
+
+/// Emitted every time funds leave the contract towards an owner's payout destination
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnerPayout {
+    #[topic]
+    pub owner: Address,
+    pub destination: Address,
+    pub amount: i128,
+}
+
+/// Emitted when an owner registers or clears a payout destination
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutDestinationSet {
+    #[topic]
+    pub owner: Address,
+    pub destination: Address,
+}
+
+/// Emitted when a co-owner withdraws their share of a car's revenue
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryPayout {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub beneficiary: Address,
+    pub destination: Address,
+    pub amount: i128,
+}
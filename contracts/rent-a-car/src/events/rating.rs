@@ -0,0 +1,28 @@
+use soroban_sdk::{contractevent, Address, BytesN};
+
+/// Emitted when the renter rates a completed rental's car and owner
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenterReview {
+    #[topic]
+    pub renter: Address,
+    #[topic]
+    pub owner: Address,
+    pub rental_index: u32,
+    pub car_score: u32,
+    pub owner_score: u32,
+    pub review_hash: BytesN<32>,
+}
+
+/// Emitted when the owner rates the renter of a completed rental
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnerReview {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub renter: Address,
+    pub rental_index: u32,
+    pub score: u32,
+    pub review_hash: BytesN<32>,
+}
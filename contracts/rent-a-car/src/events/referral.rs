@@ -0,0 +1,12 @@
+use soroban_sdk::{contractevent, Address};
+
+/// Emitted when part of a rental's admin commission is credited to a referrer
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralReward {
+    #[topic]
+    pub referrer: Address,
+    #[topic]
+    pub referred: Address,
+    pub amount: i128,
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{contractevent, Address};
+
+/// Emitted when part of a rental payment is returned to the renter
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalRefund {
+    #[topic]
+    pub renter: Address,
+    #[topic]
+    pub owner: Address,
+    pub amount: i128,
+    pub commission: i128,
+}
+
+/// Emitted when a rental's security deposit goes back to the renter
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositReturned {
+    #[topic]
+    pub renter: Address,
+    #[topic]
+    pub owner: Address,
+    pub amount: i128,
+}
@@ -1,7 +1,30 @@
-use soroban_sdk::{Address, Env, Vec, String};
+use soroban_sdk::{Address, BytesN, Env, Vec, String};
+use crate::storage::types::block_reason::BlockReason;
 use crate::storage::types::car_status::CarStatus;
+use crate::storage::types::discount::Discount;
+use crate::storage::types::early_return::EarlyReturnPolicy;
+use crate::storage::types::licence_class::LicenceClass;
+use crate::storage::types::reputation_tier::ReputationTier;
+use crate::storage::structs::beneficiary::Beneficiary;
 use crate::storage::structs::car::Car;
+use crate::storage::structs::attestation::Attestation;
+use crate::storage::structs::damage_claim::DamageClaim;
+use crate::storage::structs::insurance::InsuranceConfig;
+use crate::storage::structs::access_grant::AccessGrant;
+use crate::storage::types::access_role::AccessRole;
+use crate::storage::structs::rating::RatingSummary;
+use crate::storage::structs::renter_rules::RenterRules;
+use crate::storage::structs::block::{Appeal, BlockRecord};
+use crate::storage::structs::car_details::CarDetails;
+use crate::storage::structs::zone::{CarLocation, Zone};
+use crate::storage::structs::car_filter::{CarFilter, CarPage};
+use crate::storage::structs::loyalty::{LoyaltyAccount, LoyaltyConfig};
+use crate::storage::structs::price_rules::PriceRules;
+use crate::storage::structs::promo_code::PromoCode;
+use crate::storage::structs::referral::{ReferralAccount, ReferralConfig};
+use crate::storage::structs::rental_options::RentalOptions;
 use crate::storage::structs::rental::Rental;
+use crate::storage::structs::rental_record::RentalRecord;
 
 pub trait RentACarContractTrait {
     fn __constructor(env: &Env, admin: Address, token: Address);
@@ -9,15 +32,150 @@ pub trait RentACarContractTrait {
     fn get_admin(env: &Env) -> Address;
     fn add_car(env: &Env, owner: Address, brand: String, model: String, color: String, passengers: u32, ac: bool, price_per_day: i128, commission_percentage: i128);
     fn get_car_status(env: &Env, owner: Address) -> CarStatus;
-    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128);
+    fn rental(env: &Env, renter: Address, owner: Address, duration_hours: u32, amount: i128, options: RentalOptions);
     fn remove_car(env: &Env, owner: Address);
     fn payout_owner(env: &Env, owner: Address, amount: i128);
+
+    // Owner payouts
+    fn payout_all(env: &Env, owner: Address) -> i128;
+    fn batch_payout(env: &Env, owners: Vec<Address>) -> i128;
+    fn set_payout_destination(env: &Env, owner: Address, destination: Address);
+    fn get_payout_destination(env: &Env, owner: Address) -> Address;
+
+    // Co-owned cars and revenue splitting
+    fn set_car_beneficiaries(env: &Env, owner: Address, beneficiaries: Vec<Beneficiary>);
+    fn get_car_beneficiaries(env: &Env, owner: Address) -> Vec<Beneficiary>;
+    fn get_beneficiary_balance(env: &Env, owner: Address, beneficiary: Address) -> i128;
+    fn withdraw_beneficiary_share(env: &Env, beneficiary: Address, owner: Address) -> i128;
+
+    // Streaming settlement
+    fn set_streaming_settlement(env: &Env, owner: Address, enabled: bool);
+    fn get_streaming_settlement(env: &Env, owner: Address) -> bool;
+    fn get_streamed_claimable(env: &Env, renter: Address, owner: Address) -> i128;
+    fn claim_streamed(env: &Env, owner: Address, renter: Address) -> i128;
+
+    // Duration-based pricing
+    fn set_car_pricing(env: &Env, owner: Address, price_per_hour: i128, min_duration_hours: u32, max_duration_hours: u32);
+    fn set_price_rules(env: &Env, owner: Address, rules: PriceRules);
+    fn get_price_rules(env: &Env, owner: Address) -> PriceRules;
+    fn quote_rental(env: &Env, owner: Address, start_time: u64, duration_hours: u32) -> i128;
+
+    // Ratings
+    fn rate_as_renter(env: &Env, renter: Address, rental_index: u32, car_score: u32, owner_score: u32, review_hash: BytesN<32>);
+    fn rate_as_owner(env: &Env, owner: Address, renter: Address, rental_index: u32, score: u32, review_hash: BytesN<32>);
+    fn get_renter_rating(env: &Env, renter: Address) -> RatingSummary;
+    fn get_owner_rating(env: &Env, owner: Address) -> RatingSummary;
+    fn get_car_rating(env: &Env, owner: Address) -> RatingSummary;
+
+    // Reputation-based renter rules
+    fn set_renter_rules(env: &Env, owner: Address, rules: RenterRules);
+    fn get_renter_rules(env: &Env, owner: Address) -> RenterRules;
+    fn set_additional_driver_fee(env: &Env, owner: Address, fee: i128);
+    fn get_additional_driver_fee(env: &Env, owner: Address) -> i128;
+    fn get_reputation_tier(env: &Env, renter: Address) -> ReputationTier;
+    fn get_required_deposit(env: &Env, renter: Address, owner: Address) -> i128;
+
+    // Car ownership tokens
+    fn balance(env: &Env, account: Address) -> u32;
+    fn owner_of(env: &Env, token_id: u32) -> Address;
+    fn transfer(env: &Env, from: Address, to: Address, token_id: u32);
+    fn transfer_from(env: &Env, spender: Address, from: Address, to: Address, token_id: u32);
+    fn approve(env: &Env, approver: Address, approved: Address, token_id: u32, live_until_ledger: u32);
+    fn approve_for_all(env: &Env, owner: Address, operator: Address, live_until_ledger: u32);
+    fn get_approved(env: &Env, token_id: u32) -> Option<Address>;
+    fn is_approved_for_all(env: &Env, owner: Address, operator: Address) -> bool;
+    fn name(env: &Env) -> String;
+    fn symbol(env: &Env) -> String;
+    fn token_uri(env: &Env, token_id: u32) -> String;
+    fn get_car_token(env: &Env, owner: Address) -> u32;
+    fn get_token_car(env: &Env, token_id: u32) -> Address;
+
+    // Car access
+    fn grant_car_access(env: &Env, owner: Address, grantee: Address, role: AccessRole, valid_from: u64, valid_until: u64);
+    fn revoke_car_access(env: &Env, owner: Address, grantee: Address);
+    fn get_car_access_grants(env: &Env, owner: Address) -> Vec<AccessGrant>;
+    fn set_car_maintenance(env: &Env, owner: Address, in_maintenance: bool);
+    fn can_access(env: &Env, car: Address, address: Address, timestamp: u64) -> bool;
+
+    // Rental passes
+    fn set_rental_pass_contract(env: &Env, pass: Address);
+    fn get_rental_pass_contract(env: &Env) -> Option<Address>;
+
+    // Insurance coverage
+    fn set_insurance_config(env: &Env, config: InsuranceConfig);
+    fn get_insurance_config(env: &Env) -> InsuranceConfig;
+
+    // Damage claims
+    fn set_arbiter(env: &Env, arbiter: Address);
+    fn get_arbiter(env: &Env) -> Address;
+    fn open_damage_claim(env: &Env, owner: Address, renter: Address, rental_index: u32, amount: i128, evidence_hash: BytesN<32>);
+    fn accept_damage_claim(env: &Env, renter: Address, rental_index: u32);
+    fn contest_damage_claim(env: &Env, renter: Address, rental_index: u32, evidence_hash: BytesN<32>);
+    fn resolve_damage_claim(env: &Env, arbiter: Address, renter: Address, rental_index: u32, awarded: i128);
+    fn get_damage_claim(env: &Env, renter: Address, rental_index: u32) -> DamageClaim;
+    fn release_deposit(env: &Env, renter: Address, rental_index: u32);
+
+    // Blocklist and appeals
+    fn block_address(env: &Env, address: Address, reason: BlockReason, freeze_payouts: bool);
+    fn unblock_address(env: &Env, address: Address);
+    fn get_block(env: &Env, address: Address) -> BlockRecord;
+    fn is_blocked(env: &Env, address: Address) -> bool;
+    fn file_appeal(env: &Env, address: Address, evidence_hash: BytesN<32>);
+    fn resolve_appeal(env: &Env, address: Address, accept: bool);
+    fn get_appeal(env: &Env, address: Address) -> Appeal;
+
+    // Driver verification
+    fn set_kyc_required(env: &Env, required: bool);
+    fn get_kyc_required(env: &Env) -> bool;
+    fn set_verifier(env: &Env, verifier: Address, approved: bool);
+    fn is_verifier(env: &Env, verifier: Address) -> bool;
+    fn attest_licence(env: &Env, verifier: Address, driver: Address, classes: Vec<LicenceClass>, expires_at: u64);
+    fn revoke_attestation(env: &Env, verifier: Address, driver: Address);
+    fn get_attestation(env: &Env, driver: Address) -> Attestation;
+    fn set_required_licence(env: &Env, owner: Address, class: Option<LicenceClass>);
+    fn get_required_licence(env: &Env, owner: Address) -> Option<LicenceClass>;
+
+    // Zones and one-way rentals
+    fn add_zone(env: &Env, zone_id: u32, name: String, pickup_point: String);
+    fn get_zone(env: &Env, zone_id: u32) -> Zone;
+    fn set_relocation_fee(env: &Env, from_zone: u32, to_zone: u32, fee: i128);
+    fn remove_relocation_fee(env: &Env, from_zone: u32, to_zone: u32);
+    fn get_relocation_fee(env: &Env, from_zone: u32, to_zone: u32) -> Option<i128>;
+    fn set_car_zone(env: &Env, owner: Address, zone_id: u32, pickup_point: String);
+    fn get_car_location(env: &Env, owner: Address) -> CarLocation;
+
+    // Car metadata
+    fn set_car_details(env: &Env, owner: Address, details: CarDetails);
+    fn get_car_details(env: &Env, owner: Address) -> CarDetails;
+    fn get_car_by_vin(env: &Env, vin: String) -> Address;
+
+    // Promo codes
+    fn create_promo_code(env: &Env, creator: Address, code_hash: BytesN<32>, discount: Discount, max_uses: u32, per_renter_limit: u32, expires_at: u64);
+    fn disable_promo_code(env: &Env, caller: Address, code_hash: BytesN<32>);
+    fn get_promo_code(env: &Env, code_hash: BytesN<32>) -> PromoCode;
+
+    // Early return
+    fn set_early_return_policy(env: &Env, owner: Address, policy: EarlyReturnPolicy);
+    fn get_early_return_policy(env: &Env, owner: Address) -> EarlyReturnPolicy;
     
     // Admin commission management
     fn set_commission(env: &Env, commission: i128);
     fn get_commission(env: &Env) -> i128;
     fn withdraw_admin_commissions(env: &Env);
     fn get_admin_commission_balance(env: &Env) -> i128;
+
+    // Loyalty points
+    fn set_loyalty_config(env: &Env, earn_bps: u32, point_value: i128);
+    fn get_loyalty_config(env: &Env) -> LoyaltyConfig;
+    fn get_loyalty_account(env: &Env, renter: Address) -> LoyaltyAccount;
+
+    // Referral rewards
+    fn set_referral_config(env: &Env, share_bps: u32, cap_per_referrer: i128);
+    fn get_referral_config(env: &Env) -> ReferralConfig;
+    fn register_referrer(env: &Env, user: Address, referrer: Address);
+    fn get_referrer(env: &Env, user: Address) -> Option<Address>;
+    fn get_referral_account(env: &Env, referrer: Address) -> ReferralAccount;
+    fn claim_referral_rewards(env: &Env, referrer: Address) -> i128;
     
     // New query functions
     fn get_car(env: &Env, owner: Address) -> Car;
@@ -25,10 +183,20 @@ pub trait RentACarContractTrait {
     fn get_rental(env: &Env, renter: Address, owner: Address) -> Rental;
     fn has_rental(env: &Env, renter: Address, owner: Address) -> bool;
     fn get_available_to_withdraw(env: &Env, owner: Address) -> i128;
+
+    // Renter rental history
+    fn get_active_rentals(env: &Env, renter: Address) -> Vec<Address>;
+    fn get_rental_history(env: &Env, renter: Address, start: u32, limit: u32) -> Vec<RentalRecord>;
+    fn get_rental_history_count(env: &Env, renter: Address) -> u32;
     
     // Rental lifecycle management
     fn end_rental(env: &Env, renter: Address, owner: Address);
+    fn extend_rental(env: &Env, renter: Address, owner: Address, extra_hours: u32, amount: i128);
+    fn add_rental_driver(env: &Env, renter: Address, owner: Address, driver: Address);
+    fn remove_rental_driver(env: &Env, renter: Address, owner: Address, driver: Address);
     
-    // Get all car owners for discovery
+    // Car discovery
     fn get_all_car_owners(env: &Env) -> Vec<Address>;
+    fn get_car_owners_count(env: &Env) -> u32;
+    fn list_cars(env: &Env, filter: CarFilter, cursor: u32, limit: u32) -> CarPage;
 }
\ No newline at end of file
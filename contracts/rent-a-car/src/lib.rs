@@ -1,6 +1,8 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
 mod contract;
+mod events;
 mod interface;
 mod storage;
 mod methods;
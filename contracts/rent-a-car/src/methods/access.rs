@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::car_token::car_holder;
+use crate::storage::{
+    car::read_car,
+    car_access::read_access_grants,
+    rental::{read_car_renter, read_rental},
+    types::{access_role::AccessRole, car_status::CarStatus},
+};
+
+/// Whether `address` may open `owner`'s car at `timestamp`. The renter and their additional drivers
+/// have the car to themselves during the rental window, and keep access past it until the car is
+/// returned; outside the window the holder, their drivers and, while the car is in maintenance,
+/// the maintenance crew can open it.
+pub(crate) fn has_access(env: &Env, owner: &Address, address: &Address, timestamp: u64) -> bool {
+    let Ok(car) = read_car(env, owner) else {
+        return false;
+    };
+
+    if let Some(renter) = read_car_renter(env, owner) {
+        if let Ok(rental) = read_rental(env, &renter, owner) {
+            let is_renter = &renter == address || rental.additional_drivers.contains(address);
+            if (rental.start_time..=rental.end_time).contains(&timestamp) {
+                return is_renter;
+            }
+            // An overdue renter still has to bring the car back
+            if timestamp > rental.end_time && is_renter {
+                return true;
+            }
+        }
+    }
+
+    if &car_holder(env, owner) == address {
+        return true;
+    }
+    read_access_grants(env, owner).iter().any(|grant| {
+        &grant.grantee == address
+            && grant.valid_from <= timestamp
+            && timestamp < grant.valid_until
+            && (grant.role == AccessRole::Driver || car.car_status == CarStatus::Maintenance)
+    })
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{blocklist::read_block, types::errors::RentACarError};
+
+/// Rejects blocked addresses from renting and listing
+pub(crate) fn validate_not_blocked(env: &Env, address: &Address) -> Result<(), RentACarError> {
+    if read_block(env, address).is_ok() {
+        return Err(RentACarError::AddressBlocked);
+    }
+    Ok(())
+}
+
+/// Whether payouts to this address are withheld by a block
+pub(crate) fn payouts_frozen(env: &Env, address: &Address) -> bool {
+    read_block(env, address).map(|block| block.freeze_payouts).unwrap_or(false)
+}
+
+pub(crate) fn validate_payouts_not_frozen(env: &Env, address: &Address) -> Result<(), RentACarError> {
+    if payouts_frozen(env, address) {
+        return Err(RentACarError::PayoutsFrozen);
+    }
+    Ok(())
+}
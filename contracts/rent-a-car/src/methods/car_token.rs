@@ -0,0 +1,70 @@
+use soroban_sdk::{Address, Env};
+use stellar_tokens::non_fungible::{burnable::emit_burn, Base};
+
+use crate::methods::blocklist::validate_not_blocked;
+use crate::methods::payout::payout;
+use crate::storage::{
+    beneficiary::remove_car_beneficiaries,
+    car::read_car,
+    car_access::remove_access_grants,
+    car_token::{read_car_token, read_token_car, read_token_owner, remove_car_token, write_car_token},
+    payout::remove_payout_destination,
+    types::{car_status::CarStatus, errors::RentACarError},
+};
+
+pub const TOKEN_NAME: &str = "Stellar Motors Car";
+pub const TOKEN_SYMBOL: &str = "SMCAR";
+
+/// Address entitled to a car's listing and payouts: the holder of its ownership token.
+/// Cars that were removed have no token left and fall back to the address they were listed under.
+pub(crate) fn car_holder(env: &Env, owner: &Address) -> Address {
+    read_car_token(env, owner)
+        .and_then(|token_id| read_token_owner(env, token_id))
+        .unwrap_or_else(|_| owner.clone())
+}
+
+/// Requires authorization of the car's current holder and returns it
+pub(crate) fn require_car_holder(env: &Env, owner: &Address) -> Address {
+    let holder = car_holder(env, owner);
+    holder.require_auth();
+    holder
+}
+
+/// Mints the ownership token for a newly listed car to the address it is listed under
+pub(crate) fn mint_car_token(env: &Env, owner: &Address) -> u32 {
+    let token_id = Base::sequential_mint(env, owner);
+    write_car_token(env, owner, token_id);
+    token_id
+}
+
+/// Burns a removed car's ownership token
+pub(crate) fn burn_car_token(env: &Env, owner: &Address) {
+    let Ok(token_id) = read_car_token(env, owner) else {
+        return;
+    };
+    let holder = Base::owner_of(env, token_id);
+
+    remove_car_token(env, owner, token_id);
+    Base::update(env, Some(&holder), None, token_id);
+    emit_burn(env, &holder, token_id);
+}
+
+/// Settles the car behind `token_id` before it changes hands. Earnings accrued so far are paid out
+/// to the current holder; the payout destination, co-owner shares and access grants they set up
+/// do not carry over. Ownership, approvals and the transfer event are left to `Base`.
+pub(crate) fn settle_car_transfer(env: &Env, to: &Address, token_id: u32) {
+    validate_not_blocked(env, to).unwrap_or_else(|e| panic!("{:?}", e));
+
+    let owner = read_token_car(env, token_id).unwrap_or_else(|e| panic!("{:?}", e));
+    let car = read_car(env, &owner).unwrap_or_else(|_| panic!("Car not found"));
+    if car.car_status == CarStatus::Rented {
+        panic!("{:?}", RentACarError::CarStillRented);
+    }
+
+    if car.available_to_withdraw > 0 {
+        payout(env, &owner, car.available_to_withdraw);
+    }
+    remove_payout_destination(env, &owner);
+    remove_car_beneficiaries(env, &owner);
+    remove_access_grants(env, &owner);
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, Env};
+
+use crate::events::rental::DepositReturned;
+use crate::methods::blocklist::validate_payouts_not_frozen;
+use crate::methods::token::token::token_transfer;
+use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
+
+/// Returns a held security deposit to the renter. Fails while the renter's payouts are frozen.
+pub(crate) fn return_deposit(env: &Env, renter: &Address, owner: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    validate_payouts_not_frozen(env, renter).unwrap_or_else(|e| panic!("{:?}", e));
+
+    let contract_balance = read_contract_balance(env)
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic!("Underflow in contract balance"));
+    write_contract_balance(env, &contract_balance);
+
+    token_transfer(env, &env.current_contract_address(), renter, &amount);
+
+    DepositReturned {
+        renter: renter.clone(),
+        owner: owner.clone(),
+        amount,
+    }
+    .publish(env);
+}
@@ -0,0 +1,75 @@
+use soroban_sdk::{Env, Map, Vec};
+
+use crate::methods::blocklist::validate_not_blocked;
+use crate::methods::car_token::car_holder;
+use crate::storage::{
+    car::read_car,
+    car_details::read_car_details,
+    car_owners::{get_owner_at, get_owners_count},
+    structs::{
+        car::Car,
+        car_filter::{CarFilter, CarListing, CarPage},
+    },
+    types::owner_registry::OwnerRegistry,
+};
+
+/// Maximum number of index entries examined by a single discovery call
+pub const MAX_SCAN: u32 = 100;
+
+pub(crate) fn matches_filter(car: &Car, filter: &CarFilter) -> bool {
+    if !filter.statuses.is_empty() && !filter.statuses.contains(&car.car_status) {
+        return false;
+    }
+    if let Some(ac) = filter.ac {
+        if car.ac != ac {
+            return false;
+        }
+    }
+
+    // Zone queries walk the zone index, so the brand still has to be checked
+    if let Some(brand) = &filter.brand {
+        if car.brand != *brand {
+            return false;
+        }
+    }
+
+    car.passengers >= filter.min_passengers
+        && car.price_per_day >= filter.min_price
+        && (filter.max_price == 0 || car.price_per_day <= filter.max_price)
+}
+
+/// Walks the owner registry (or the zone or brand index when one is given) from `cursor`,
+/// collecting up to `limit` matching cars. At most `MAX_SCAN` entries are examined per call,
+/// so a page may come back short with a cursor to continue from. Registries are swap-removed,
+/// so a car removed between calls can move a not-yet-visited owner behind the cursor.
+pub(crate) fn list_cars(env: &Env, filter: &CarFilter, cursor: u32, limit: u32) -> CarPage {
+    let registry = match (&filter.zone, &filter.brand) {
+        (Some(zone_id), _) => OwnerRegistry::Zone(*zone_id),
+        (None, Some(brand)) => OwnerRegistry::Brand(brand.clone()),
+        (None, None) => OwnerRegistry::All,
+    };
+
+    let total = get_owners_count(env, &registry);
+    let scan_end = cursor.saturating_add(MAX_SCAN).min(total);
+    let mut cars = Vec::new(env);
+    let mut details = Map::new(env);
+    let mut position = cursor;
+
+    while position < scan_end && cars.len() < limit {
+        if let Some(owner) = get_owner_at(env, &registry, position) {
+            if let Ok(car) = read_car(env, &owner) {
+                // Cars of blocked owners are delisted
+                if matches_filter(&car, filter) && validate_not_blocked(env, &car_holder(env, &owner)).is_ok() {
+                    if let Ok(car_details) = read_car_details(env, &owner) {
+                        details.set(owner.clone(), car_details);
+                    }
+                    cars.push_back(CarListing { owner, car });
+                }
+            }
+        }
+        position += 1;
+    }
+
+    let next_cursor = if position < total { Some(position) } else { None };
+    CarPage { cars, details, next_cursor }
+}
@@ -0,0 +1,72 @@
+use soroban_sdk::{Address, Env};
+
+use crate::events::dispute::DepositSettled;
+use crate::methods::deposit::return_deposit;
+use crate::methods::insurance::claim_coverage;
+use crate::methods::car_token::car_holder;
+use crate::methods::payout::transfer_to_former_holder;
+use crate::methods::revenue::split_revenue;
+use crate::storage::{
+    car::{read_car, write_car},
+    rental_history::{read_rental_record, write_rental_record},
+    structs::rental_record::RentalRecord,
+    types::errors::RentACarError,
+};
+
+/// How long after the car is returned the owner can open a damage claim; the deposit is held until then
+pub const CLAIM_WINDOW_SECONDS: u64 = 3 * 24 * 60 * 60;
+
+/// How long the renter has to accept or contest a claim before the arbiter can decide without them
+pub const RESPONSE_WINDOW_SECONDS: u64 = 3 * 24 * 60 * 60;
+
+/// Loads a completed rental whose deposit is still held
+pub(crate) fn held_deposit_record(env: &Env, renter: &Address, rental_index: u32) -> Result<RentalRecord, RentACarError> {
+    let record = read_rental_record(env, renter, rental_index)?;
+    if record.deposit_settled {
+        return Err(RentACarError::DepositAlreadySettled);
+    }
+    Ok(record)
+}
+
+/// Pays `awarded` to the owner's earnings, insurance coverage first and the held deposit after,
+/// and returns the rest of the deposit to the renter. Returns the part paid by the insurance pool.
+pub(crate) fn settle_deposit(env: &Env, renter: &Address, rental_index: u32, awarded: i128) -> i128 {
+    let mut record = held_deposit_record(env, renter, rental_index).unwrap_or_else(|e| panic!("{:?}", e));
+
+    let covered = claim_coverage(env, &record.insurance_pool, awarded.min(record.coverage), record.coverage);
+    // The deposit also absorbs whatever a short pool could not pay
+    let from_deposit = (awarded - covered).min(record.deposit);
+    let returned = record.deposit - from_deposit;
+    let paid = covered + from_deposit;
+
+    record.deposit_settled = true;
+    write_rental_record(env, renter, rental_index, &record);
+
+    if paid > 0 {
+        // Credited and split among co-owners like rental earnings while the rental's holder still
+        // holds the car; holders who delisted or transferred the car since are paid directly
+        match read_car(env, &record.owner) {
+            Ok(mut car) if car_holder(env, &record.owner) == record.holder => {
+                car.available_to_withdraw = car.available_to_withdraw
+                    .checked_add(paid)
+                    .unwrap_or_else(|| panic!("Balance overflow"));
+                split_revenue(env, &record.owner, &mut car, paid);
+                write_car(env, &record.owner, &car);
+            }
+            _ => transfer_to_former_holder(env, &record.owner, &record.holder, paid),
+        }
+    }
+    return_deposit(env, renter, &record.owner, returned);
+
+    DepositSettled {
+        renter: renter.clone(),
+        owner: record.owner,
+        rental_index,
+        awarded: paid,
+        covered,
+        returned,
+    }
+    .publish(env);
+
+    covered
+}
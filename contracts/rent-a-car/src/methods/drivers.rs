@@ -0,0 +1,29 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::{
+    blocklist::validate_not_blocked, car_token::car_holder, kyc::validate_verified_driver,
+    validation::MAX_ADDITIONAL_DRIVERS,
+};
+use crate::storage::{structs::rental::Rental, types::errors::RentACarError};
+
+/// Checks that `driver` can be added to the renter's rental of `owner`'s car: not already on it,
+/// not the holder, not blocked, and verified for the car until the rental ends.
+pub(crate) fn validate_additional_driver(
+    env: &Env,
+    renter: &Address,
+    owner: &Address,
+    rental: &Rental,
+    driver: &Address,
+) -> Result<(), RentACarError> {
+    if driver == renter || rental.additional_drivers.contains(driver) {
+        return Err(RentACarError::DuplicateDriver);
+    }
+    if driver == &car_holder(env, owner) {
+        return Err(RentACarError::SelfRental);
+    }
+    if rental.additional_drivers.len() >= MAX_ADDITIONAL_DRIVERS {
+        return Err(RentACarError::TooManyDrivers);
+    }
+    validate_not_blocked(env, driver)?;
+    validate_verified_driver(env, driver, owner, rental.end_time)
+}
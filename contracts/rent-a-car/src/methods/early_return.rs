@@ -0,0 +1,26 @@
+use crate::methods::pricing::{HOURS_PER_DAY, SECONDS_PER_DAY};
+use crate::storage::{structs::rental::Rental, types::early_return::EarlyReturnPolicy};
+
+/// Base amount to refund when a rental is returned at `now` under `policy`.
+/// Only whole unused days count; partial days are kept by the owner.
+pub(crate) fn early_return_refund(rental: &Rental, policy: &EarlyReturnPolicy, now: u64) -> i128 {
+    if now >= rental.end_time || rental.duration_hours == 0 {
+        return 0;
+    }
+
+    let unused_days = (rental.end_time - now) / SECONDS_PER_DAY;
+    let unused_hours = (unused_days * HOURS_PER_DAY as u64) as i128;
+    let refund = rental.amount
+        .checked_mul(unused_hours)
+        .unwrap_or_else(|| panic!("Refund calculation overflow"))
+        / rental.duration_hours as i128;
+
+    match policy {
+        EarlyReturnPolicy::NoRefund => 0,
+        EarlyReturnPolicy::ProRated => refund,
+        EarlyReturnPolicy::ProRatedWithFee(fee_bps) => {
+            let fee = refund * (*fee_bps as i128) / 10000_i128;
+            refund - fee
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+use crate::methods::payment::calculate_commission;
+use crate::storage::{
+    contract_balance::{read_contract_balance, write_contract_balance},
+    insurance::read_insurance_config,
+};
+
+/// Entry points of the insurance-pool contract used by rentals
+#[allow(dead_code)]
+#[contractclient(name = "InsurancePoolClient")]
+pub trait InsurancePool {
+    fn collect_premium(env: Env, payer: Address, amount: i128, coverage: i128);
+    fn pay_claim(env: Env, to: Address, amount: i128, coverage: i128) -> i128;
+    fn release_coverage(env: Env, coverage: i128);
+}
+
+/// Premium on `base_amount`, the coverage it buys at the current rates and the pool selling it;
+/// 0, 0 and no pool when not insured
+pub(crate) fn quote_coverage(env: &Env, base_amount: i128, insured: bool) -> (i128, i128, Option<Address>) {
+    if !insured {
+        return (0, 0, None);
+    }
+
+    let config = read_insurance_config(env).unwrap_or_else(|e| panic!("{:?}", e));
+    (calculate_commission(base_amount, config.premium_bps as i128), config.max_coverage, Some(config.pool))
+}
+
+/// Charges the renter the premium, paid straight into the pool so it never sits in this contract,
+/// and has the pool reserve the coverage sold
+pub(crate) fn pay_premium(env: &Env, pool: &Option<Address>, renter: &Address, premium: i128, coverage: i128) {
+    let Some(pool) = pool else {
+        return;
+    };
+    if premium <= 0 && coverage <= 0 {
+        return;
+    }
+
+    InsurancePoolClient::new(env, pool).collect_premium(renter, &premium, &coverage);
+}
+
+/// Asks the pool that sold `coverage` to cover `amount` of a damage claim, or only releases the coverage
+/// when nothing is claimed; the payout lands in this contract's balance.
+/// Returns what the pool paid, which can fall short when it lacks liquidity.
+pub(crate) fn claim_coverage(env: &Env, pool: &Option<Address>, amount: i128, coverage: i128) -> i128 {
+    let Some(pool) = pool else {
+        return 0;
+    };
+    if coverage <= 0 {
+        return 0;
+    }
+
+    let client = InsurancePoolClient::new(env, pool);
+    if amount <= 0 {
+        client.release_coverage(&coverage);
+        return 0;
+    }
+    let paid = client.pay_claim(&env.current_contract_address(), &amount, &coverage);
+
+    let contract_balance = read_contract_balance(env)
+        .checked_add(paid)
+        .unwrap_or_else(|| panic!("Contract balance overflow"));
+    write_contract_balance(env, &contract_balance);
+
+    paid
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    kyc::{is_verifier, read_attestation, read_kyc_required, read_required_licence},
+    types::errors::RentACarError,
+};
+
+/// Checks that `driver` holds a licence attestation from a still-approved verifier that
+/// stays valid until `until` and covers the class the car requires, when verification is on.
+pub(crate) fn validate_verified_driver(env: &Env, driver: &Address, owner: &Address, until: u64) -> Result<(), RentACarError> {
+    if !read_kyc_required(env) {
+        return Ok(());
+    }
+
+    let attestation = read_attestation(env, driver).map_err(|_| RentACarError::DriverNotVerified)?;
+    if !is_verifier(env, &attestation.verifier) {
+        return Err(RentACarError::DriverNotVerified);
+    }
+    if attestation.expires_at < until {
+        return Err(RentACarError::AttestationExpired);
+    }
+    if let Some(class) = read_required_licence(env, owner) {
+        if !attestation.classes.contains(&class) {
+            return Err(RentACarError::LicenceClassMismatch);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::loyalty::{read_loyalty_account, read_loyalty_config, write_loyalty_account};
+
+/// Awards points for a completed rental, proportional to the base amount the renter ended up paying
+pub(crate) fn award_loyalty_points(env: &Env, renter: &Address, amount_paid: i128) {
+    let config = read_loyalty_config(env);
+    let points = amount_paid
+        .checked_mul(config.earn_bps as i128)
+        .unwrap_or_else(|| panic!("Loyalty points overflow"))
+        / 10000_i128;
+    if points <= 0 {
+        return;
+    }
+
+    let mut account = read_loyalty_account(env, renter);
+    account.points += points;
+    account.total_earned += points;
+    account.rentals_rewarded += 1;
+    account.last_earned_at = env.ledger().timestamp();
+    write_loyalty_account(env, renter, &account);
+}
+
+/// Burns `points` from the renter's balance and returns the discount they are worth.
+/// The discount must leave something to pay on `price`.
+pub(crate) fn redeem_loyalty_points(env: &Env, renter: &Address, points: i128, price: i128) -> i128 {
+    if points <= 0 {
+        return 0;
+    }
+
+    let config = read_loyalty_config(env);
+    if config.point_value <= 0 {
+        panic!("Loyalty redemption is disabled");
+    }
+
+    let mut account = read_loyalty_account(env, renter);
+    if points > account.points {
+        panic!("Insufficient loyalty points");
+    }
+
+    let discount = points
+        .checked_mul(config.point_value)
+        .unwrap_or_else(|| panic!("Loyalty discount overflow"));
+    if discount >= price {
+        panic!("Loyalty discount exceeds rental price");
+    }
+
+    account.points -= points;
+    account.total_redeemed += points;
+    write_loyalty_account(env, renter, &account);
+
+    discount
+}
@@ -1,2 +1,25 @@
+pub mod access;
+pub mod blocklist;
+pub mod car_token;
+pub mod deposit;
+pub mod discovery;
+pub mod dispute;
+pub mod drivers;
+pub mod early_return;
+pub mod insurance;
+pub mod kyc;
+pub mod loyalty;
+pub mod payment;
+pub mod payout;
+pub mod pricing;
+pub mod promo;
+pub mod rating;
+pub mod referral;
+pub mod refund;
+pub mod rental_pass;
+pub mod reputation;
+pub mod revenue;
+pub mod streaming;
 pub mod token;
 pub mod validation;
+pub mod zone;
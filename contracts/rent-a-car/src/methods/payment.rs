@@ -0,0 +1,43 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::token::token::token_transfer;
+use crate::storage::{
+    commission::{read_admin_commission_balance, write_admin_commission_balance},
+    contract_balance::{read_contract_balance, write_contract_balance},
+};
+
+/// Commission on a base amount; `commission_percentage` is in basis points (500 = 5%)
+pub(crate) fn calculate_commission(amount: i128, commission_percentage: i128) -> i128 {
+    (amount
+        .checked_mul(commission_percentage)
+        .unwrap_or_else(|| panic!("Commission calculation overflow")))
+        .checked_div(10000_i128)
+        .unwrap_or_else(|| panic!("Division by zero"))
+}
+
+/// Pulls `amount + commission` from the renter into the contract and books the commission for the admin.
+/// Returns the total charged.
+pub(crate) fn collect_payment(env: &Env, renter: &Address, amount: i128, commission: i128) -> i128 {
+    // Calculate total amount (deposit + commission)
+    let total_amount = amount
+        .checked_add(commission)
+        .unwrap_or_else(|| panic!("Amount overflow when adding commission"));
+
+    // Update contract balance with total amount (deposit + commission)
+    let contract_balance = read_contract_balance(env)
+        .checked_add(total_amount)
+        .unwrap_or_else(|| panic!("Contract balance overflow"));
+
+    // Accumulate commission for admin
+    let admin_commission_balance = read_admin_commission_balance(env)
+        .checked_add(commission)
+        .unwrap_or_else(|| panic!("Admin commission balance overflow"));
+
+    write_admin_commission_balance(env, &admin_commission_balance);
+    write_contract_balance(env, &contract_balance);
+
+    // Transfer total amount (deposit + commission) from renter to contract
+    token_transfer(env, renter, &env.current_contract_address(), &total_amount);
+
+    total_amount
+}
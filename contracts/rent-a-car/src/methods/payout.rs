@@ -0,0 +1,100 @@
+use soroban_sdk::{Address, Env};
+
+use crate::events::payout::{BeneficiaryPayout, OwnerPayout};
+use crate::methods::blocklist::validate_payouts_not_frozen;
+use crate::methods::car_token::car_holder;
+use crate::methods::token::token::token_transfer;
+use crate::methods::validation::validate_withdrawal_amount;
+use crate::storage::{
+    beneficiary::{read_beneficiary_balance, write_beneficiary_balance},
+    car::{read_car, write_car},
+    contract_balance::{read_contract_balance, write_contract_balance},
+    payout::get_payout_destination,
+};
+use crate::storage::types::car_status::CarStatus;
+
+/// Moves `amount` from the owner's withdrawable balance to their payout destination.
+/// Callers are responsible for authorization.
+pub(crate) fn payout(env: &Env, owner: &Address, amount: i128) {
+    let mut car = read_car(env, owner).unwrap_or_else(|_| panic!("Car not found"));
+
+    // 🚗 Retiro de owners restringido: Solo permitir retiro cuando el auto esté devuelto
+    if car.car_status == CarStatus::Rented {
+        panic!("Car must be returned before owner can withdraw funds");
+    }
+
+    // Validate withdrawal amount doesn't exceed available
+    validate_withdrawal_amount(amount, car.available_to_withdraw)
+        .unwrap_or_else(|e| panic!("{:?}", e));
+
+    car.available_to_withdraw = car.available_to_withdraw
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic!("Underflow in available_to_withdraw"));
+
+    write_car(env, owner, &car);
+
+    transfer_to_owner(env, owner, amount);
+}
+
+/// Sends already-settled owner funds to the car holder's payout destination.
+/// The caller must have removed `amount` from whichever balance tracked it.
+pub(crate) fn transfer_to_owner(env: &Env, owner: &Address, amount: i128) {
+    let holder = car_holder(env, owner);
+    let destination = get_payout_destination(env, owner).unwrap_or_else(|| holder.clone());
+    send_owner_funds(env, owner, &holder, destination, amount);
+}
+
+/// Sends already-settled funds of `owner`'s car to a former holder, who no longer holds the car
+/// or its payout destination. The caller must have removed `amount` from whichever balance tracked it.
+pub(crate) fn transfer_to_former_holder(env: &Env, owner: &Address, holder: &Address, amount: i128) {
+    send_owner_funds(env, owner, holder, holder.clone(), amount);
+}
+
+fn send_owner_funds(env: &Env, owner: &Address, holder: &Address, destination: Address, amount: i128) {
+    validate_payouts_not_frozen(env, holder).unwrap_or_else(|e| panic!("{:?}", e));
+
+    let contract_balance = read_contract_balance(env)
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic!("Underflow in contract balance"));
+    write_contract_balance(env, &contract_balance);
+
+    token_transfer(env, &env.current_contract_address(), &destination, &amount);
+
+    OwnerPayout {
+        owner: owner.clone(),
+        destination,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Pays a co-owner their whole settled share of a car's revenue and returns the amount.
+/// Callers are responsible for authorization.
+pub(crate) fn beneficiary_payout(env: &Env, owner: &Address, beneficiary: &Address) -> i128 {
+    validate_payouts_not_frozen(env, beneficiary).unwrap_or_else(|e| panic!("{:?}", e));
+
+    let amount = read_beneficiary_balance(env, owner, beneficiary);
+    if amount <= 0 {
+        panic!("No funds available to withdraw");
+    }
+
+    let contract_balance = read_contract_balance(env)
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic!("Underflow in contract balance"));
+
+    write_beneficiary_balance(env, owner, beneficiary, &0);
+    write_contract_balance(env, &contract_balance);
+
+    // Payout destinations belong to cars, a co-owner's share goes to the co-owner themselves
+    token_transfer(env, &env.current_contract_address(), beneficiary, &amount);
+
+    BeneficiaryPayout {
+        owner: owner.clone(),
+        beneficiary: beneficiary.clone(),
+        destination: beneficiary.clone(),
+        amount,
+    }
+    .publish(env);
+
+    amount
+}
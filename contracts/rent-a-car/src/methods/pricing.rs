@@ -0,0 +1,76 @@
+use crate::storage::{
+    structs::{car::Car, price_rules::PriceRules},
+    types::errors::RentACarError,
+};
+
+pub const SECONDS_PER_HOUR: u64 = 3600;
+pub const HOURS_PER_DAY: u32 = 24;
+pub const SECONDS_PER_DAY: u64 = SECONDS_PER_HOUR * HOURS_PER_DAY as u64;
+
+/// Base price (without commission) of renting `car` for `duration_hours` from `start_time`.
+///
+/// Every rental day is priced on its own: `price_per_day` adjusted by the weekend surcharge
+/// and the first matching season for the calendar day it starts on. Leftover hours are charged
+/// at `price_per_hour`, never more than one extra day, with the adjustments of the day they fall in.
+/// Long-stay discounts then apply to the total.
+pub fn rental_price(car: &Car, rules: &PriceRules, start_time: u64, duration_hours: u32) -> Result<i128, RentACarError> {
+    let days = duration_hours / HOURS_PER_DAY;
+    let hours = (duration_hours % HOURS_PER_DAY) as i128;
+
+    let mut total: i128 = 0;
+    for day in 0..days {
+        let day_start = start_time + day as u64 * SECONDS_PER_DAY;
+        total = total
+            .checked_add(adjusted_price(car.price_per_day, rules, day_start)?)
+            .ok_or(RentACarError::AmountMismatch)?;
+    }
+
+    if hours > 0 {
+        let hours_price = car.price_per_hour
+            .checked_mul(hours)
+            .ok_or(RentACarError::AmountMismatch)?
+            .min(car.price_per_day);
+        let day_start = start_time + days as u64 * SECONDS_PER_DAY;
+        total = total
+            .checked_add(adjusted_price(hours_price, rules, day_start)?)
+            .ok_or(RentACarError::AmountMismatch)?;
+    }
+
+    let discount_bps = if days >= 30 {
+        rules.monthly_discount_bps
+    } else if days >= 7 {
+        rules.weekly_discount_bps
+    } else {
+        0
+    };
+
+    Ok(total - total * discount_bps as i128 / 10000_i128)
+}
+
+/// Applies the weekend and seasonal adjustments for the day starting at `day_start`
+fn adjusted_price(price: i128, rules: &PriceRules, day_start: u64) -> Result<i128, RentACarError> {
+    let mut bps: i128 = 10000;
+    if is_weekend(day_start) {
+        bps += rules.weekend_surcharge_bps as i128;
+    }
+    if let Some(season) = rules.seasons.iter().find(|s| day_start >= s.start && day_start < s.end) {
+        bps += season.adjustment_bps as i128;
+    }
+
+    Ok(price
+        .checked_mul(bps.max(0))
+        .ok_or(RentACarError::AmountMismatch)?
+        / 10000_i128)
+}
+
+/// Whether a ledger timestamp falls on a Saturday or Sunday (UTC)
+pub fn is_weekend(timestamp: u64) -> bool {
+    // 1970-01-01 was a Thursday; 0 = Sunday .. 6 = Saturday
+    let weekday = (timestamp / SECONDS_PER_DAY + 4) % 7;
+    weekday == 0 || weekday == 6
+}
+
+/// Converts a duration in hours to ledger seconds
+pub fn duration_seconds(duration_hours: u32) -> u64 {
+    duration_hours as u64 * SECONDS_PER_HOUR
+}
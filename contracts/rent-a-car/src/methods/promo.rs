@@ -0,0 +1,53 @@
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use crate::storage::{
+    promo_code::{read_promo_code, read_promo_renter_uses, write_promo_code, write_promo_renter_uses},
+    types::discount::Discount,
+};
+
+/// On-chain key of a plain-text promo code
+pub(crate) fn promo_code_hash(env: &Env, code: &String) -> BytesN<32> {
+    env.crypto().sha256(&code.to_bytes()).to_bytes()
+}
+
+/// Amount taken off `price` by `discount`, always leaving something to pay
+pub(crate) fn discount_amount(discount: &Discount, price: i128) -> i128 {
+    let value = match discount {
+        Discount::Percent(bps) => price * (*bps as i128) / 10000_i128,
+        Discount::Fixed(value) => *value,
+    };
+    value.min(price - 1).max(0)
+}
+
+/// Checks that `code` can be used by `renter` on `owner`'s car, records the use
+/// and returns the discount on `price`
+pub(crate) fn redeem_promo_code(env: &Env, code: &String, renter: &Address, owner: &Address, price: i128) -> i128 {
+    let code_hash = promo_code_hash(env, code);
+    let mut promo = read_promo_code(env, &code_hash).unwrap_or_else(|_| panic!("Promo code not found"));
+
+    if !promo.active {
+        panic!("Promo code is disabled");
+    }
+    if promo.expires_at != 0 && env.ledger().timestamp() > promo.expires_at {
+        panic!("Promo code expired");
+    }
+    if let Some(car_owner) = &promo.car_owner {
+        if car_owner != owner {
+            panic!("Promo code not valid for this car");
+        }
+    }
+    if promo.max_uses != 0 && promo.uses >= promo.max_uses {
+        panic!("Promo code usage limit reached");
+    }
+
+    let renter_uses = read_promo_renter_uses(env, &code_hash, renter);
+    if promo.per_renter_limit != 0 && renter_uses >= promo.per_renter_limit {
+        panic!("Promo code limit reached for renter");
+    }
+
+    promo.uses += 1;
+    write_promo_code(env, &code_hash, &promo);
+    write_promo_renter_uses(env, &code_hash, renter, &(renter_uses + 1));
+
+    discount_amount(&promo.discount, price)
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    rental_history::read_rental_record,
+    structs::rental_record::RentalRecord,
+    types::errors::RentACarError,
+};
+
+/// How long after the car is returned both parties can rate the rental
+pub const RATING_WINDOW_SECONDS: u64 = 14 * 24 * 60 * 60;
+
+/// Loads a completed rental that can still be rated
+pub(crate) fn rateable_record(env: &Env, renter: &Address, index: u32) -> Result<RentalRecord, RentACarError> {
+    let record = read_rental_record(env, renter, index)?;
+    if env.ledger().timestamp() > record.returned_at + RATING_WINDOW_SECONDS {
+        return Err(RentACarError::RatingWindowClosed);
+    }
+    Ok(record)
+}
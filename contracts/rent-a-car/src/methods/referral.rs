@@ -0,0 +1,64 @@
+use soroban_sdk::{Address, Env};
+
+use crate::events::referral::ReferralReward;
+use crate::storage::{
+    commission::{read_admin_commission_balance, write_admin_commission_balance},
+    referral::{read_referral_account, read_referral_config, read_referrer, write_referral_account},
+};
+
+/// Credits the renter's and the car holder's referrers with the referral share of a rental's
+/// admin commission, moving it out of the admin commission balance. When both were referred
+/// they split one share, so referrals never take more than `share_bps` of the commission.
+/// Funds stay in the contract until the referrer claims them. Returns the total credited.
+pub(crate) fn credit_referral_rewards(env: &Env, renter: &Address, holder: &Address, commission: i128) -> i128 {
+    let config = read_referral_config(env);
+    if config.share_bps == 0 || commission <= 0 {
+        return 0;
+    }
+
+    let share = commission * config.share_bps as i128 / 10000_i128;
+    let renter_referrer = read_referrer(env, renter);
+    let holder_referrer = read_referrer(env, holder);
+
+    match (renter_referrer, holder_referrer) {
+        (Some(renter_referrer), Some(holder_referrer)) => {
+            let half = share / 2;
+            credit_referrer(env, &renter_referrer, renter, share - half, config.cap_per_referrer)
+                + credit_referrer(env, &holder_referrer, holder, half, config.cap_per_referrer)
+        }
+        (Some(referrer), None) => credit_referrer(env, &referrer, renter, share, config.cap_per_referrer),
+        (None, Some(referrer)) => credit_referrer(env, &referrer, holder, share, config.cap_per_referrer),
+        (None, None) => 0,
+    }
+}
+
+fn credit_referrer(env: &Env, referrer: &Address, referred: &Address, share: i128, cap: i128) -> i128 {
+    let mut account = read_referral_account(env, referrer);
+
+    let amount = if cap > 0 {
+        share.min(cap - account.total_earned).max(0)
+    } else {
+        share
+    };
+    if amount == 0 {
+        return 0;
+    }
+
+    let admin_commission_balance = read_admin_commission_balance(env)
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic!("Underflow in admin commission balance"));
+    write_admin_commission_balance(env, &admin_commission_balance);
+
+    account.balance += amount;
+    account.total_earned += amount;
+    write_referral_account(env, referrer, &account);
+
+    ReferralReward {
+        referrer: referrer.clone(),
+        referred: referred.clone(),
+        amount,
+    }
+    .publish(env);
+
+    amount
+}
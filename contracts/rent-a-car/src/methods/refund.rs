@@ -0,0 +1,61 @@
+use soroban_sdk::{Address, Env};
+
+use crate::events::rental::RentalRefund;
+use crate::methods::blocklist::validate_payouts_not_frozen;
+use crate::methods::token::token::token_transfer;
+use crate::storage::{
+    structs::rental::Rental,
+    commission::{read_admin_commission_balance, write_admin_commission_balance},
+    contract_balance::{read_contract_balance, write_contract_balance},
+};
+
+/// Returns `amount` of the base payment plus up to `commission` of the admin commission to the renter.
+/// The caller must already have taken the base amount out of any owner balance.
+///
+/// The commission part is limited to what the admin balance still holds: commission already
+/// withdrawn by the admin is not clawed back. Fails while the renter's payouts are frozen.
+/// Returns the commission refunded.
+pub(crate) fn refund_renter(env: &Env, renter: &Address, owner: &Address, amount: i128, commission: i128) -> i128 {
+    let admin_commission_balance = read_admin_commission_balance(env);
+    let commission = commission.min(admin_commission_balance).max(0);
+
+    let total = amount
+        .checked_add(commission)
+        .unwrap_or_else(|| panic!("Refund overflow"));
+    if total <= 0 {
+        return 0;
+    }
+    validate_payouts_not_frozen(env, renter).unwrap_or_else(|e| panic!("{:?}", e));
+
+    let admin_commission_balance = admin_commission_balance - commission;
+    let contract_balance = read_contract_balance(env)
+        .checked_sub(total)
+        .unwrap_or_else(|| panic!("Underflow in contract balance"));
+
+    write_admin_commission_balance(env, &admin_commission_balance);
+    write_contract_balance(env, &contract_balance);
+
+    token_transfer(env, &env.current_contract_address(), renter, &total);
+
+    RentalRefund {
+        renter: renter.clone(),
+        owner: owner.clone(),
+        amount,
+        commission,
+    }
+    .publish(env);
+
+    commission
+}
+
+/// Share of the commission the admin kept on the rental, after its referral rewards,
+/// that corresponds to `refund` out of the rental amount. Referral rewards are not clawed back.
+pub(crate) fn commission_share(rental: &Rental, refund: i128) -> i128 {
+    if rental.amount <= 0 {
+        return 0;
+    }
+    (rental.commission - rental.referral_rewards)
+        .checked_mul(refund)
+        .unwrap_or_else(|| panic!("Commission refund overflow"))
+        / rental.amount
+}
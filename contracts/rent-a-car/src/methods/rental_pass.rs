@@ -0,0 +1,40 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+use crate::storage::{
+    car_token::read_car_token, rental_pass::read_rental_pass_contract, structs::rental::Rental,
+};
+
+/// Entry points of the rental-pass contract used by rentals
+#[allow(dead_code)]
+#[contractclient(name = "RentalPassClient")]
+pub trait RentalPass {
+    fn mint(env: Env, to: Address, car: Address, car_token_id: u32, valid_from: u64, valid_until: u64) -> u32;
+    fn set_valid_until(env: Env, token_id: u32, valid_until: u64);
+    fn burn(env: Env, token_id: u32);
+}
+
+/// Issues the renter a pass for `owner`'s car over the rental window, when passes are enabled.
+/// Returns the pass contract and the pass id.
+pub(crate) fn issue_pass(env: &Env, renter: &Address, owner: &Address, start_time: u64, end_time: u64) -> (Option<Address>, Option<u32>) {
+    let Some(pass) = read_rental_pass_contract(env) else {
+        return (None, None);
+    };
+    let car_token_id = read_car_token(env, owner).unwrap_or_else(|e| panic!("{:?}", e));
+    let pass_id = RentalPassClient::new(env, &pass).mint(renter, owner, &car_token_id, &start_time, &end_time);
+
+    (Some(pass), Some(pass_id))
+}
+
+/// Moves the end of a rental's pass along with the rental
+pub(crate) fn extend_pass(env: &Env, rental: &Rental) {
+    if let (Some(pass), Some(pass_id)) = (&rental.pass_contract, rental.pass_id) {
+        RentalPassClient::new(env, pass).set_valid_until(&pass_id, &rental.end_time);
+    }
+}
+
+/// Burns the pass of a returned car in the contract that issued it
+pub(crate) fn burn_pass(env: &Env, rental: &Rental) {
+    if let (Some(pass), Some(pass_id)) = (&rental.pass_contract, rental.pass_id) {
+        RentalPassClient::new(env, pass).burn(&pass_id);
+    }
+}
@@ -0,0 +1,57 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    rating::read_renter_rating,
+    rental_history::read_rental_history_count,
+    renter_rules::read_renter_rules,
+    types::{errors::RentACarError, reputation_tier::ReputationTier},
+};
+
+pub const SILVER_MIN_RENTALS: u32 = 3;
+pub const SILVER_MIN_RATING_X100: u32 = 400;
+pub const GOLD_MIN_RENTALS: u32 = 10;
+pub const GOLD_MIN_RATING_X100: u32 = 450;
+
+/// Tier of a renter from their completed rentals and the ratings owners gave them
+pub(crate) fn reputation_tier(env: &Env, renter: &Address) -> ReputationTier {
+    let completed = read_rental_history_count(env, renter);
+    let rating = read_renter_rating(env, renter);
+    if completed == 0 || rating.count == 0 {
+        return ReputationTier::New;
+    }
+
+    if completed >= GOLD_MIN_RENTALS && rating.average_x100 >= GOLD_MIN_RATING_X100 {
+        ReputationTier::Gold
+    } else if completed >= SILVER_MIN_RENTALS && rating.average_x100 >= SILVER_MIN_RATING_X100 {
+        ReputationTier::Silver
+    } else {
+        ReputationTier::Bronze
+    }
+}
+
+/// Checks the car's renter requirements and returns the security deposit the renter must put down
+pub(crate) fn required_deposit(env: &Env, renter: &Address, owner: &Address) -> Result<i128, RentACarError> {
+    let rules = read_renter_rules(env, owner);
+
+    if read_rental_history_count(env, renter) < rules.min_completed_rentals {
+        return Err(RentACarError::NotEnoughRentals);
+    }
+    if rules.min_rating_x100 > 0 {
+        let rating = read_renter_rating(env, renter);
+        if rating.count == 0 || rating.average_x100 < rules.min_rating_x100 {
+            return Err(RentACarError::RatingTooLow);
+        }
+    }
+
+    let multiplier_bps = match reputation_tier(env, renter) {
+        ReputationTier::New => rules.new_multiplier_bps,
+        ReputationTier::Bronze => rules.bronze_multiplier_bps,
+        ReputationTier::Silver => rules.silver_multiplier_bps,
+        ReputationTier::Gold => rules.gold_multiplier_bps,
+    };
+
+    Ok(rules.base_deposit
+        .checked_mul(multiplier_bps as i128)
+        .ok_or(RentACarError::InvalidAmount)?
+        / 10000_i128)
+}
@@ -0,0 +1,43 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    beneficiary::{read_beneficiary_balance, read_car_beneficiaries, write_beneficiary_balance},
+    structs::car::Car,
+};
+
+/// Moves `amount` of a completed rental's revenue out of the car's withdrawable balance
+/// and into the beneficiaries' balances, pro rata to their shares.
+/// Cars without beneficiaries keep the revenue on `available_to_withdraw`.
+///
+/// Each share is rounded down; the first listed beneficiary receives whatever is left
+/// so that the split always adds up to exactly `amount`.
+pub(crate) fn split_revenue(env: &Env, owner: &Address, car: &mut Car, amount: i128) {
+    let beneficiaries = read_car_beneficiaries(env, owner);
+    if beneficiaries.is_empty() || amount <= 0 {
+        return;
+    }
+
+    car.available_to_withdraw = car.available_to_withdraw
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic!("Underflow in available_to_withdraw"));
+
+    let mut distributed: i128 = 0;
+    for beneficiary in beneficiaries.iter().skip(1) {
+        let share = amount
+            .checked_mul(beneficiary.share_bps as i128)
+            .unwrap_or_else(|| panic!("Revenue split overflow"))
+            / 10000_i128;
+        credit_beneficiary(env, owner, &beneficiary.address, share);
+        distributed += share;
+    }
+
+    let first = beneficiaries.get_unchecked(0);
+    credit_beneficiary(env, owner, &first.address, amount - distributed);
+}
+
+fn credit_beneficiary(env: &Env, owner: &Address, beneficiary: &Address, amount: i128) {
+    let balance = read_beneficiary_balance(env, owner, beneficiary)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic!("Beneficiary balance overflow"));
+    write_beneficiary_balance(env, owner, beneficiary, &balance);
+}
@@ -0,0 +1,33 @@
+use crate::storage::structs::rental::Rental;
+
+/// Portion of a streaming rental's amount earned by the owner at `now`.
+/// The original booking accrues linearly up to the first extension and each extension over its own
+/// window, so a cheaper extension never lowers what has already accrued. Capped at the full amount.
+pub(crate) fn accrued_amount(rental: &Rental, now: u64) -> i128 {
+    let mut base_amount = rental.amount;
+    let mut accrued: i128 = 0;
+    for extension in rental.extensions.iter() {
+        base_amount -= extension.amount;
+        accrued += linear_accrual(extension.amount, extension.start_time, extension.end_time, now);
+    }
+
+    let base_end = rental.extensions.first().map_or(rental.end_time, |extension| extension.start_time);
+    accrued + linear_accrual(base_amount, rental.start_time, base_end, now)
+}
+
+/// Portion of `amount` earned at `now` when it accrues linearly from `start` to `end`
+fn linear_accrual(amount: i128, start: u64, end: u64, now: u64) -> i128 {
+    if now >= end || end <= start {
+        return amount;
+    }
+    if now <= start {
+        return 0;
+    }
+
+    let elapsed = (now - start) as i128;
+    let duration = (end - start) as i128;
+    amount
+        .checked_mul(elapsed)
+        .unwrap_or_else(|| panic!("Accrual calculation overflow"))
+        / duration
+}
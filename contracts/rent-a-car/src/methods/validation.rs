@@ -1,5 +1,34 @@
-use soroban_sdk::Env;
-use crate::storage::types::errors::RentACarError;
+use soroban_sdk::{String, Vec};
+use crate::methods::pricing::HOURS_PER_DAY;
+use crate::storage::{
+    structs::{beneficiary::Beneficiary, car::Car, car_details::CarDetails, insurance::InsuranceConfig, price_rules::PriceRules, renter_rules::RenterRules},
+    types::{discount::Discount, early_return::EarlyReturnPolicy, errors::RentACarError},
+};
+
+/// Upper bound on co-owners per car so revenue splitting stays cheap
+pub const MAX_BENEFICIARIES: u32 = 10;
+
+/// Upper bound on drivers and maintenance grants per car, they are scanned on every access check
+pub const MAX_ACCESS_GRANTS: u32 = 10;
+
+/// Upper bound on additional drivers per rental
+pub const MAX_ADDITIONAL_DRIVERS: u32 = 4;
+
+/// Upper bound on seasonal rates per car, they are scanned for every rental day
+pub const MAX_SEASONS: u32 = 12;
+
+/// Upper bound on records returned by a paginated query
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Upper bound on a reputation tier's deposit multiplier (10x)
+pub const MAX_DEPOSIT_MULTIPLIER_BPS: u32 = 100_000;
+
+pub const VIN_LENGTH: u32 = 17;
+pub const MIN_CAR_YEAR: u32 = 1900;
+pub const MAX_CAR_YEAR: u32 = 2100;
+pub const MAX_PLATE_LENGTH: u32 = 16;
+pub const MAX_LOCATION_LENGTH: u32 = 128;
+pub const MAX_URI_LENGTH: u32 = 256;
 
 /// Validates that an amount is positive
 pub fn validate_positive_amount(amount: i128) -> Result<(), RentACarError> {
@@ -17,20 +46,50 @@ pub fn validate_price(price_per_day: i128) -> Result<(), RentACarError> {
     Ok(())
 }
 
-/// Validates that rental days is positive
-pub fn validate_rental_days(days: u32) -> Result<(), RentACarError> {
-    if days == 0 {
-        return Err(RentACarError::InvalidDays);
+/// Validates a rental duration against the car's limits.
+/// Cars without an hourly rate can only be rented for whole days.
+pub fn validate_rental_duration(car: &Car, duration_hours: u32) -> Result<(), RentACarError> {
+    if duration_hours == 0 || duration_hours < car.min_duration_hours {
+        return Err(RentACarError::InvalidDuration);
+    }
+    if car.max_duration_hours > 0 && duration_hours > car.max_duration_hours {
+        return Err(RentACarError::InvalidDuration);
+    }
+    if car.price_per_hour == 0 && duration_hours % HOURS_PER_DAY != 0 {
+        return Err(RentACarError::InvalidDuration);
+    }
+    Ok(())
+}
+
+/// Validates an extension of `extra_hours` on top of a rental of `current_hours`.
+/// The car's minimum doesn't apply to extensions, its maximum applies to the extended total.
+pub fn validate_extension_duration(car: &Car, current_hours: u32, extra_hours: u32) -> Result<(), RentACarError> {
+    let total_hours = current_hours
+        .checked_add(extra_hours)
+        .ok_or(RentACarError::InvalidDuration)?;
+    if extra_hours == 0 || (car.max_duration_hours > 0 && total_hours > car.max_duration_hours) {
+        return Err(RentACarError::InvalidDuration);
+    }
+    if car.price_per_hour == 0 && extra_hours % HOURS_PER_DAY != 0 {
+        return Err(RentACarError::InvalidDuration);
+    }
+    Ok(())
+}
+
+/// Validates the hourly rate and duration limits an owner sets on a car
+pub fn validate_duration_pricing(price_per_hour: i128, min_duration_hours: u32, max_duration_hours: u32) -> Result<(), RentACarError> {
+    if price_per_hour < 0 {
+        return Err(RentACarError::InvalidPrice);
+    }
+    if max_duration_hours > 0 && max_duration_hours < min_duration_hours {
+        return Err(RentACarError::InvalidDuration);
     }
     Ok(())
 }
 
 /// Validates that payment amount matches expected price
-pub fn validate_payment_amount(amount: i128, price_per_day: i128, days: u32) -> Result<(), RentACarError> {
+pub fn validate_payment_amount(amount: i128, expected_amount: i128) -> Result<(), RentACarError> {
     validate_positive_amount(amount)?;
-    let expected_amount = price_per_day
-        .checked_mul(days as i128)
-        .ok_or(RentACarError::AmountMismatch)?;
     
     if amount != expected_amount {
         return Err(RentACarError::AmountMismatch);
@@ -54,3 +113,143 @@ pub fn validate_not_self_rental(renter: &soroban_sdk::Address, owner: &soroban_s
     }
     Ok(())
 }
+
+/// Validates a beneficiary list: bounded size, positive shares summing to 10000 bps, no duplicates
+pub fn validate_beneficiaries(beneficiaries: &Vec<Beneficiary>) -> Result<(), RentACarError> {
+    if beneficiaries.is_empty() || beneficiaries.len() > MAX_BENEFICIARIES {
+        return Err(RentACarError::InvalidShares);
+    }
+
+    let mut total_bps: u32 = 0;
+    for (i, beneficiary) in beneficiaries.iter().enumerate() {
+        if beneficiary.share_bps == 0 {
+            return Err(RentACarError::InvalidShares);
+        }
+        total_bps = total_bps
+            .checked_add(beneficiary.share_bps)
+            .ok_or(RentACarError::InvalidShares)?;
+
+        for other in beneficiaries.iter().skip(i + 1) {
+            if other.address == beneficiary.address {
+                return Err(RentACarError::DuplicateBeneficiary);
+            }
+        }
+    }
+
+    if total_bps != 10000 {
+        return Err(RentACarError::InvalidShares);
+    }
+    Ok(())
+}
+
+/// Validates that an early-return fee is a valid basis-point value
+pub fn validate_early_return_policy(policy: &EarlyReturnPolicy) -> Result<(), RentACarError> {
+    if let EarlyReturnPolicy::ProRatedWithFee(fee_bps) = policy {
+        if *fee_bps > 10000 {
+            return Err(RentACarError::InvalidFee);
+        }
+    }
+    Ok(())
+}
+
+/// Validates owner price rules: discounts within 100%, seasons non-empty and never below a free day
+pub fn validate_price_rules(rules: &PriceRules) -> Result<(), RentACarError> {
+    if rules.weekly_discount_bps > 10000 || rules.monthly_discount_bps > 10000 {
+        return Err(RentACarError::InvalidPriceRule);
+    }
+    if rules.seasons.len() > MAX_SEASONS {
+        return Err(RentACarError::InvalidPriceRule);
+    }
+    for season in rules.seasons.iter() {
+        if season.start >= season.end || season.adjustment_bps < -10000 {
+            return Err(RentACarError::InvalidPriceRule);
+        }
+    }
+    Ok(())
+}
+
+/// Validates a promo code discount; a code can never make a rental free
+pub fn validate_discount(discount: &Discount) -> Result<(), RentACarError> {
+    match discount {
+        Discount::Percent(bps) if *bps == 0 || *bps >= 10000 => Err(RentACarError::InvalidDiscount),
+        Discount::Fixed(value) if *value <= 0 => Err(RentACarError::InvalidDiscount),
+        _ => Ok(()),
+    }
+}
+
+/// Pickup points are free text shown to renters, bounded like the rest of the car metadata
+pub fn validate_pickup_point(pickup_point: &String) -> Result<(), RentACarError> {
+    if pickup_point.len() > MAX_LOCATION_LENGTH {
+        return Err(RentACarError::InvalidCarDetails);
+    }
+    Ok(())
+}
+
+/// Validates car metadata bounds. VINs are 17 characters of digits and capital letters
+/// other than I, O and Q; uniqueness is checked against the VIN index by the caller.
+pub fn validate_car_details(details: &CarDetails) -> Result<(), RentACarError> {
+    if details.year < MIN_CAR_YEAR || details.year > MAX_CAR_YEAR {
+        return Err(RentACarError::InvalidCarDetails);
+    }
+    if details.license_plate.is_empty() || details.license_plate.len() > MAX_PLATE_LENGTH {
+        return Err(RentACarError::InvalidCarDetails);
+    }
+    if details.photo_uri.len() > MAX_URI_LENGTH {
+        return Err(RentACarError::InvalidCarDetails);
+    }
+    if details.vin.len() != VIN_LENGTH {
+        return Err(RentACarError::InvalidCarDetails);
+    }
+
+    let mut vin = [0u8; VIN_LENGTH as usize];
+    details.vin.copy_into_slice(&mut vin);
+    let valid = vin.iter().all(|c| {
+        c.is_ascii_digit() || (c.is_ascii_uppercase() && !matches!(c, b'I' | b'O' | b'Q'))
+    });
+    if !valid {
+        return Err(RentACarError::InvalidCarDetails);
+    }
+    Ok(())
+}
+
+/// Validates a 1-5 star rating
+pub fn validate_score(score: u32) -> Result<(), RentACarError> {
+    if !(1..=5).contains(&score) {
+        return Err(RentACarError::InvalidRating);
+    }
+    Ok(())
+}
+
+/// Validates that an access grant's window is non-empty and has not already ended
+pub fn validate_access_window(valid_from: u64, valid_until: u64, now: u64) -> Result<(), RentACarError> {
+    if valid_until <= valid_from || valid_until <= now {
+        return Err(RentACarError::InvalidAccessGrant);
+    }
+    Ok(())
+}
+
+/// Validates the insurance premium rate and coverage cap
+pub fn validate_insurance_config(config: &InsuranceConfig) -> Result<(), RentACarError> {
+    if config.premium_bps > 10000 || config.max_coverage < 0 {
+        return Err(RentACarError::InvalidInsuranceConfig);
+    }
+    Ok(())
+}
+
+/// Validates a car's renter requirements and deposit multipliers
+pub fn validate_renter_rules(rules: &RenterRules) -> Result<(), RentACarError> {
+    if rules.base_deposit < 0 || rules.min_rating_x100 > 500 {
+        return Err(RentACarError::InvalidRenterRules);
+    }
+
+    let multipliers = [
+        rules.new_multiplier_bps,
+        rules.bronze_multiplier_bps,
+        rules.silver_multiplier_bps,
+        rules.gold_multiplier_bps,
+    ];
+    if multipliers.iter().any(|bps| *bps > MAX_DEPOSIT_MULTIPLIER_BPS) {
+        return Err(RentACarError::InvalidRenterRules);
+    }
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use soroban_sdk::{Address, Env, String};
+
+use crate::storage::{
+    car_owners::{add_owner, remove_owner},
+    structs::zone::CarLocation,
+    types::owner_registry::OwnerRegistry,
+    zone::{read_car_location, read_relocation_fee, read_zone, write_car_location},
+};
+
+/// Places a car in a zone, keeping the per-zone owner registry in sync.
+/// An empty pickup point falls back to the zone's default one.
+pub(crate) fn move_car_to_zone(env: &Env, owner: &Address, zone_id: u32, pickup_point: String) {
+    let zone = read_zone(env, zone_id).unwrap_or_else(|_| panic!("Zone not found"));
+
+    if let Ok(previous) = read_car_location(env, owner) {
+        remove_owner(env, &OwnerRegistry::Zone(previous.zone_id), owner);
+    }
+    add_owner(env, &OwnerRegistry::Zone(zone_id), owner);
+
+    let pickup_point = if pickup_point.is_empty() { zone.pickup_point } else { pickup_point };
+    write_car_location(env, owner, &CarLocation { zone_id, pickup_point });
+}
+
+/// Relocation fee for a rental returned in `return_zone`; 0 when no return zone is given.
+/// A return zone equal to the car's own zone is rejected, leave it unset instead.
+pub(crate) fn one_way_fee(env: &Env, owner: &Address, return_zone: Option<u32>) -> i128 {
+    let return_zone = match return_zone {
+        Some(zone_id) => zone_id,
+        None => return 0,
+    };
+
+    let location = read_car_location(env, owner)
+        .unwrap_or_else(|_| panic!("Car is not assigned to a zone"));
+    if location.zone_id == return_zone {
+        panic!("Return zone must differ from the pickup zone");
+    }
+
+    read_relocation_fee(env, location.zone_id, return_zone)
+        .unwrap_or_else(|| panic!("One-way rentals are not offered between these zones"))
+}
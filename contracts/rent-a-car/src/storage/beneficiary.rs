@@ -0,0 +1,34 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{structs::beneficiary::Beneficiary, types::storage::DataKey};
+
+pub(crate) fn read_car_beneficiaries(env: &Env, owner: &Address) -> Vec<Beneficiary> {
+    env.storage()
+        .instance()
+        .get(&DataKey::CarBeneficiaries(owner.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub(crate) fn write_car_beneficiaries(env: &Env, owner: &Address, beneficiaries: &Vec<Beneficiary>) {
+    env.storage().instance().set(&DataKey::CarBeneficiaries(owner.clone()), beneficiaries);
+}
+
+pub(crate) fn remove_car_beneficiaries(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::CarBeneficiaries(owner.clone()));
+}
+
+pub(crate) fn read_beneficiary_balance(env: &Env, owner: &Address, beneficiary: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BeneficiaryBalance(owner.clone(), beneficiary.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_beneficiary_balance(env: &Env, owner: &Address, beneficiary: &Address, amount: &i128) {
+    let key = DataKey::BeneficiaryBalance(owner.clone(), beneficiary.clone());
+    if *amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, amount);
+    }
+}
@@ -0,0 +1,32 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::block::{Appeal, BlockRecord},
+    types::{errors::RentACarError, storage::DataKey},
+};
+
+pub(crate) fn read_block(env: &Env, address: &Address) -> Result<BlockRecord, RentACarError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Blocked(address.clone()))
+        .ok_or(RentACarError::NotFound)
+}
+
+pub(crate) fn write_block(env: &Env, address: &Address, block: &BlockRecord) {
+    env.storage().persistent().set(&DataKey::Blocked(address.clone()), block);
+}
+
+pub(crate) fn remove_block(env: &Env, address: &Address) {
+    env.storage().persistent().remove(&DataKey::Blocked(address.clone()));
+}
+
+pub(crate) fn read_appeal(env: &Env, address: &Address) -> Result<Appeal, RentACarError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Appeal(address.clone()))
+        .ok_or(RentACarError::NotFound)
+}
+
+pub(crate) fn write_appeal(env: &Env, address: &Address, appeal: &Appeal) {
+    env.storage().persistent().set(&DataKey::Appeal(address.clone()), appeal);
+}
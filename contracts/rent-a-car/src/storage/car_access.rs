@@ -0,0 +1,23 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{structs::access_grant::AccessGrant, types::storage::DataKey};
+
+pub(crate) fn read_access_grants(env: &Env, owner: &Address) -> Vec<AccessGrant> {
+    env.storage()
+        .instance()
+        .get(&DataKey::CarAccess(owner.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub(crate) fn write_access_grants(env: &Env, owner: &Address, grants: &Vec<AccessGrant>) {
+    let key = DataKey::CarAccess(owner.clone());
+    if grants.is_empty() {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, grants);
+    }
+}
+
+pub(crate) fn remove_access_grants(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::CarAccess(owner.clone()));
+}
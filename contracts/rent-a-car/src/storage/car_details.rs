@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, Env, String};
+
+use crate::storage::{structs::car_details::CarDetails, types::errors::RentACarError, types::storage::DataKey};
+
+pub(crate) fn read_car_details(env: &Env, owner: &Address) -> Result<CarDetails, RentACarError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarDetails(owner.clone()))
+        .ok_or(RentACarError::NotFound)
+}
+
+pub(crate) fn write_car_details(env: &Env, owner: &Address, details: &CarDetails) {
+    env.storage().persistent().set(&DataKey::CarDetails(owner.clone()), details);
+}
+
+pub(crate) fn remove_car_details(env: &Env, owner: &Address) {
+    env.storage().persistent().remove(&DataKey::CarDetails(owner.clone()));
+}
+
+/// Owner of the car registered with a VIN
+pub(crate) fn read_vin_owner(env: &Env, vin: &String) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::VinOwner(vin.clone()))
+}
+
+pub(crate) fn write_vin_owner(env: &Env, vin: &String, owner: &Address) {
+    env.storage().persistent().set(&DataKey::VinOwner(vin.clone()), owner);
+}
+
+pub(crate) fn remove_vin_owner(env: &Env, vin: &String) {
+    env.storage().persistent().remove(&DataKey::VinOwner(vin.clone()));
+}
@@ -1,55 +1,96 @@
 use soroban_sdk::{Address, Env, Vec};
 
-use crate::storage::types::storage::DataKey;
+use crate::storage::types::{owner_registry::OwnerRegistry, storage::DataKey};
+
+// Each registry is an indexed set: position -> owner, owner -> position and a count.
+// Adding appends at the end and removing swaps the last owner into the freed position,
+// so every operation touches a constant number of entries regardless of registry size.
+
+/// Number of owners in a registry
+pub(crate) fn get_owners_count(env: &Env, registry: &OwnerRegistry) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnersCount(registry.clone()))
+        .unwrap_or(0)
+}
+
+/// Owner stored at `position` in a registry
+pub(crate) fn get_owner_at(env: &Env, registry: &OwnerRegistry, position: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerAt(registry.clone(), position))
+}
+
+/// Check whether an owner is in a registry
+pub(crate) fn contains_owner(env: &Env, registry: &OwnerRegistry, owner: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::OwnerPosition(registry.clone(), owner.clone()))
+}
+
+/// Add an owner to a registry (no-op if already present)
+pub(crate) fn add_owner(env: &Env, registry: &OwnerRegistry, owner: &Address) {
+    if contains_owner(env, registry, owner) {
+        return;
+    }
+
+    let count = get_owners_count(env, registry);
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::OwnerAt(registry.clone(), count), owner);
+    storage.set(&DataKey::OwnerPosition(registry.clone(), owner.clone()), &count);
+    storage.set(&DataKey::OwnersCount(registry.clone()), &(count + 1));
+}
+
+/// Remove an owner from a registry, moving the last owner into its position
+pub(crate) fn remove_owner(env: &Env, registry: &OwnerRegistry, owner: &Address) {
+    let storage = env.storage().persistent();
+    let position_key = DataKey::OwnerPosition(registry.clone(), owner.clone());
+    let position: u32 = match storage.get(&position_key) {
+        Some(position) => position,
+        None => return,
+    };
+
+    let last = get_owners_count(env, registry) - 1;
+    if position != last {
+        let moved: Address = storage
+            .get(&DataKey::OwnerAt(registry.clone(), last))
+            .unwrap_or_else(|| panic!("Owner registry is corrupted"));
+        storage.set(&DataKey::OwnerAt(registry.clone(), position), &moved);
+        storage.set(&DataKey::OwnerPosition(registry.clone(), moved), &position);
+    }
+
+    storage.remove(&DataKey::OwnerAt(registry.clone(), last));
+    storage.remove(&position_key);
+    if last == 0 {
+        storage.remove(&DataKey::OwnersCount(registry.clone()));
+    } else {
+        storage.set(&DataKey::OwnersCount(registry.clone()), &last);
+    }
+}
 
 /// Get the list of all car owner addresses
 pub(crate) fn get_car_owners(env: &Env) -> Vec<Address> {
-    env.storage()
-        .instance()
-        .get(&DataKey::CarOwners)
-        .unwrap_or_else(|| Vec::new(&env))
+    let registry = OwnerRegistry::All;
+    let mut owners = Vec::new(env);
+    for position in 0..get_owners_count(env, &registry) {
+        if let Some(owner) = get_owner_at(env, &registry, position) {
+            owners.push_back(owner);
+        }
+    }
+    owners
 }
 
 /// Add a car owner to the registry
 pub(crate) fn add_car_owner(env: &Env, owner: &Address) {
-    let mut owners = get_car_owners(env);
-    
-    // Check if owner already exists
-    let mut exists = false;
-    let len = owners.len();
-    for i in 0..len {
-        if let Some(addr) = owners.get(i) {
-            if addr == *owner {
-                exists = true;
-                break;
-            }
-        }
-    }
-    
-    if !exists {
-        owners.push_back(owner.clone());
-        env.storage().instance().set(&DataKey::CarOwners, &owners);
-    }
+    add_owner(env, &OwnerRegistry::All, owner);
 }
 
 /// Remove a car owner from the registry
 pub(crate) fn remove_car_owner(env: &Env, owner: &Address) {
-    let owners = get_car_owners(env);
-    let mut new_owners = Vec::new(&env);
-    let len = owners.len();
-    
-    for i in 0..len {
-        if let Some(addr) = owners.get(i) {
-            if addr != *owner {
-                new_owners.push_back(addr);
-            }
-        }
-    }
-    
-    env.storage().instance().set(&DataKey::CarOwners, &new_owners);
+    remove_owner(env, &OwnerRegistry::All, owner);
 }
 
 /// Get the count of car owners
 pub(crate) fn get_car_owners_count(env: &Env) -> u32 {
-    get_car_owners(env).len()
+    get_owners_count(env, &OwnerRegistry::All)
 }
@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, Env};
+use stellar_tokens::non_fungible::{NFTStorageKey, NonFungibleTokenError};
+
+use crate::storage::types::car_token_key::CarTokenKey;
+
+pub(crate) fn read_car_token(env: &Env, owner: &Address) -> Result<u32, NonFungibleTokenError> {
+    env.storage()
+        .persistent()
+        .get(&CarTokenKey::CarToken(owner.clone()))
+        .ok_or(NonFungibleTokenError::NonExistentToken)
+}
+
+pub(crate) fn read_token_car(env: &Env, token_id: u32) -> Result<Address, NonFungibleTokenError> {
+    env.storage()
+        .persistent()
+        .get(&CarTokenKey::TokenCar(token_id))
+        .ok_or(NonFungibleTokenError::NonExistentToken)
+}
+
+pub(crate) fn write_car_token(env: &Env, owner: &Address, token_id: u32) {
+    env.storage().persistent().set(&CarTokenKey::CarToken(owner.clone()), &token_id);
+    env.storage().persistent().set(&CarTokenKey::TokenCar(token_id), owner);
+}
+
+pub(crate) fn remove_car_token(env: &Env, owner: &Address, token_id: u32) {
+    env.storage().persistent().remove(&CarTokenKey::CarToken(owner.clone()));
+    env.storage().persistent().remove(&CarTokenKey::TokenCar(token_id));
+}
+
+/// Current holder of `token_id`, read from the OpenZeppelin token storage without panicking
+pub(crate) fn read_token_owner(env: &Env, token_id: u32) -> Result<Address, NonFungibleTokenError> {
+    env.storage()
+        .persistent()
+        .get(&NFTStorageKey::Owner(token_id))
+        .ok_or(NonFungibleTokenError::NonExistentToken)
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::damage_claim::DamageClaim,
+    types::{errors::RentACarError, storage::DataKey},
+};
+
+pub(crate) fn read_arbiter(env: &Env) -> Result<Address, RentACarError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Arbiter)
+        .ok_or(RentACarError::NotArbiter)
+}
+
+pub(crate) fn write_arbiter(env: &Env, arbiter: &Address) {
+    env.storage().instance().set(&DataKey::Arbiter, arbiter);
+}
+
+pub(crate) fn has_damage_claim(env: &Env, renter: &Address, rental_index: u32) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::DamageClaim(renter.clone(), rental_index))
+}
+
+pub(crate) fn read_damage_claim(env: &Env, renter: &Address, rental_index: u32) -> Result<DamageClaim, RentACarError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DamageClaim(renter.clone(), rental_index))
+        .ok_or(RentACarError::NotFound)
+}
+
+pub(crate) fn write_damage_claim(env: &Env, renter: &Address, rental_index: u32, claim: &DamageClaim) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DamageClaim(renter.clone(), rental_index), claim);
+}
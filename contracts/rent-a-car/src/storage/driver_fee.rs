@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn read_driver_fee(env: &Env, owner: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DriverFee(owner.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_driver_fee(env: &Env, owner: &Address, fee: i128) {
+    env.storage().instance().set(&DataKey::DriverFee(owner.clone()), &fee);
+}
+
+pub(crate) fn remove_driver_fee(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::DriverFee(owner.clone()));
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::Env;
+
+use crate::storage::{
+    structs::insurance::InsuranceConfig,
+    types::{errors::RentACarError, storage::DataKey},
+};
+
+pub(crate) fn read_insurance_config(env: &Env) -> Result<InsuranceConfig, RentACarError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Insurance)
+        .ok_or(RentACarError::InsuranceNotConfigured)
+}
+
+pub(crate) fn write_insurance_config(env: &Env, config: &InsuranceConfig) {
+    env.storage().instance().set(&DataKey::Insurance, config);
+}
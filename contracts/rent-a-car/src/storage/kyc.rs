@@ -0,0 +1,56 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::attestation::Attestation,
+    types::{errors::RentACarError, licence_class::LicenceClass, storage::DataKey},
+};
+
+/// Whether rentals require a licence attestation
+pub(crate) fn read_kyc_required(env: &Env) -> bool {
+    env.storage().instance().get(&DataKey::KycRequired).unwrap_or(false)
+}
+
+pub(crate) fn write_kyc_required(env: &Env, required: bool) {
+    env.storage().instance().set(&DataKey::KycRequired, &required);
+}
+
+pub(crate) fn is_verifier(env: &Env, verifier: &Address) -> bool {
+    env.storage().instance().has(&DataKey::Verifier(verifier.clone()))
+}
+
+pub(crate) fn write_verifier(env: &Env, verifier: &Address, approved: bool) {
+    let key = DataKey::Verifier(verifier.clone());
+    if approved {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+pub(crate) fn read_attestation(env: &Env, driver: &Address) -> Result<Attestation, RentACarError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Attestation(driver.clone()))
+        .ok_or(RentACarError::NotFound)
+}
+
+pub(crate) fn write_attestation(env: &Env, driver: &Address, attestation: &Attestation) {
+    env.storage().persistent().set(&DataKey::Attestation(driver.clone()), attestation);
+}
+
+pub(crate) fn remove_attestation(env: &Env, driver: &Address) {
+    env.storage().persistent().remove(&DataKey::Attestation(driver.clone()));
+}
+
+/// Licence class a renter must hold to drive the car, if the owner set one
+pub(crate) fn read_required_licence(env: &Env, owner: &Address) -> Option<LicenceClass> {
+    env.storage().instance().get(&DataKey::RequiredLicence(owner.clone()))
+}
+
+pub(crate) fn write_required_licence(env: &Env, owner: &Address, class: &Option<LicenceClass>) {
+    let key = DataKey::RequiredLicence(owner.clone());
+    match class {
+        Some(class) => env.storage().instance().set(&key, class),
+        None => env.storage().instance().remove(&key),
+    }
+}
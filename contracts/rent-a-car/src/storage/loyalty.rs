@@ -0,0 +1,34 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::loyalty::{LoyaltyAccount, LoyaltyConfig},
+    types::storage::DataKey,
+};
+
+pub(crate) fn read_loyalty_config(env: &Env) -> LoyaltyConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::LoyaltyConfig)
+        .unwrap_or(LoyaltyConfig { earn_bps: 0, point_value: 0 })
+}
+
+pub(crate) fn write_loyalty_config(env: &Env, config: &LoyaltyConfig) {
+    env.storage().instance().set(&DataKey::LoyaltyConfig, config);
+}
+
+pub(crate) fn read_loyalty_account(env: &Env, renter: &Address) -> LoyaltyAccount {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LoyaltyAccount(renter.clone()))
+        .unwrap_or(LoyaltyAccount {
+            points: 0,
+            total_earned: 0,
+            total_redeemed: 0,
+            rentals_rewarded: 0,
+            last_earned_at: 0,
+        })
+}
+
+pub(crate) fn write_loyalty_account(env: &Env, renter: &Address, account: &LoyaltyAccount) {
+    env.storage().persistent().set(&DataKey::LoyaltyAccount(renter.clone()), account);
+}
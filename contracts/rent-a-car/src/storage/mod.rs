@@ -1,9 +1,29 @@
 pub mod car;
+pub mod car_access;
+pub mod car_details;
+pub mod car_token;
 pub mod admin;
 pub mod rental;
 pub mod token;
 pub mod contract_balance;
 pub mod commission;
 pub mod car_owners;
+pub mod payout;
+pub mod beneficiary;
+pub mod blocklist;
+pub mod settlement;
+pub mod price_rules;
+pub mod promo_code;
+pub mod rating;
+pub mod referral;
+pub mod loyalty;
+pub mod kyc;
+pub mod dispute;
+pub mod driver_fee;
+pub mod insurance;
+pub mod rental_history;
+pub mod rental_pass;
+pub mod renter_rules;
+pub mod zone;
 pub mod structs;
 pub mod types;
@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+/// Returns the payout destination registered for a car, if any
+pub(crate) fn get_payout_destination(env: &Env, owner: &Address) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PayoutDestination(owner.clone()))
+}
+
+pub(crate) fn write_payout_destination(env: &Env, owner: &Address, destination: &Address) {
+    env.storage().instance().set(&DataKey::PayoutDestination(owner.clone()), destination);
+}
+
+pub(crate) fn remove_payout_destination(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::PayoutDestination(owner.clone()));
+}
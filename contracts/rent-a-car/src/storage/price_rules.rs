@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::price_rules::PriceRules, types::storage::DataKey};
+
+pub(crate) fn read_price_rules(env: &Env, owner: &Address) -> PriceRules {
+    env.storage()
+        .instance()
+        .get(&DataKey::PriceRules(owner.clone()))
+        .unwrap_or_else(|| PriceRules::none(env))
+}
+
+pub(crate) fn write_price_rules(env: &Env, owner: &Address, rules: &PriceRules) {
+    env.storage().instance().set(&DataKey::PriceRules(owner.clone()), rules);
+}
+
+pub(crate) fn remove_price_rules(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::PriceRules(owner.clone()));
+}
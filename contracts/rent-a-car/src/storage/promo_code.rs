@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::storage::{structs::promo_code::PromoCode, types::errors::RentACarError, types::storage::DataKey};
+
+pub(crate) fn has_promo_code(env: &Env, code_hash: &BytesN<32>) -> bool {
+    env.storage().persistent().has(&DataKey::PromoCode(code_hash.clone()))
+}
+
+pub(crate) fn read_promo_code(env: &Env, code_hash: &BytesN<32>) -> Result<PromoCode, RentACarError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PromoCode(code_hash.clone()))
+        .ok_or(RentACarError::NotFound)
+}
+
+pub(crate) fn write_promo_code(env: &Env, code_hash: &BytesN<32>, promo: &PromoCode) {
+    env.storage().persistent().set(&DataKey::PromoCode(code_hash.clone()), promo);
+}
+
+pub(crate) fn read_promo_renter_uses(env: &Env, code_hash: &BytesN<32>, renter: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PromoRenterUses(code_hash.clone(), renter.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_promo_renter_uses(env: &Env, code_hash: &BytesN<32>, renter: &Address, uses: &u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoRenterUses(code_hash.clone(), renter.clone()), uses);
+}
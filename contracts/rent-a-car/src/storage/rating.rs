@@ -0,0 +1,49 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::rating::RatingSummary, types::storage::DataKey};
+
+fn read_summary(env: &Env, key: &DataKey) -> RatingSummary {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or(RatingSummary { count: 0, total: 0, average_x100: 0 })
+}
+
+fn add_score(env: &Env, key: &DataKey, score: u32) {
+    let mut summary = read_summary(env, key);
+    summary.count += 1;
+    summary.total += score;
+    summary.average_x100 = summary.total * 100 / summary.count;
+    env.storage().persistent().set(key, &summary);
+}
+
+/// Ratings an address received as a renter
+pub(crate) fn read_renter_rating(env: &Env, renter: &Address) -> RatingSummary {
+    read_summary(env, &DataKey::RenterRating(renter.clone()))
+}
+
+pub(crate) fn add_renter_score(env: &Env, renter: &Address, score: u32) {
+    add_score(env, &DataKey::RenterRating(renter.clone()), score);
+}
+
+/// Ratings an address received as a car owner
+pub(crate) fn read_owner_rating(env: &Env, owner: &Address) -> RatingSummary {
+    read_summary(env, &DataKey::OwnerRating(owner.clone()))
+}
+
+pub(crate) fn add_owner_score(env: &Env, owner: &Address, score: u32) {
+    add_score(env, &DataKey::OwnerRating(owner.clone()), score);
+}
+
+/// Ratings of the car currently listed by `owner`
+pub(crate) fn read_car_rating(env: &Env, owner: &Address) -> RatingSummary {
+    read_summary(env, &DataKey::CarRating(owner.clone()))
+}
+
+pub(crate) fn add_car_score(env: &Env, owner: &Address, score: u32) {
+    add_score(env, &DataKey::CarRating(owner.clone()), score);
+}
+
+pub(crate) fn remove_car_rating(env: &Env, owner: &Address) {
+    env.storage().persistent().remove(&DataKey::CarRating(owner.clone()));
+}
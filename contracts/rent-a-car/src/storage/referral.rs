@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::referral::{ReferralAccount, ReferralConfig},
+    types::storage::DataKey,
+};
+
+pub(crate) fn read_referral_config(env: &Env) -> ReferralConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReferralConfig)
+        .unwrap_or(ReferralConfig { share_bps: 0, cap_per_referrer: 0 })
+}
+
+pub(crate) fn write_referral_config(env: &Env, config: &ReferralConfig) {
+    env.storage().instance().set(&DataKey::ReferralConfig, config);
+}
+
+pub(crate) fn read_referrer(env: &Env, user: &Address) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::Referrer(user.clone()))
+}
+
+pub(crate) fn write_referrer(env: &Env, user: &Address, referrer: &Address) {
+    env.storage().persistent().set(&DataKey::Referrer(user.clone()), referrer);
+}
+
+pub(crate) fn read_referral_account(env: &Env, referrer: &Address) -> ReferralAccount {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralAccount(referrer.clone()))
+        .unwrap_or(ReferralAccount { balance: 0, total_earned: 0 })
+}
+
+pub(crate) fn write_referral_account(env: &Env, referrer: &Address, account: &ReferralAccount) {
+    env.storage().persistent().set(&DataKey::ReferralAccount(referrer.clone()), account);
+}
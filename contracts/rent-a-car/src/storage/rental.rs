@@ -17,6 +17,31 @@ pub(crate) fn read_rental(env: &Env, renter: &Address, car_owner: &Address) -> R
         .ok_or(RentACarError::NotFound)
 }
 
+const DAY_IN_LEDGERS: u32 = 17280;
+/// Car renter entries are kept alive for a month after each use, well past any rental
+pub(crate) const CAR_RENTER_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const CAR_RENTER_TTL_THRESHOLD: u32 = CAR_RENTER_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+/// Renter currently holding a car, so access checks can find the rental from the car
+pub(crate) fn read_car_renter(env: &Env, car_owner: &Address) -> Option<Address> {
+    let key = DataKey::CarRenter(car_owner.clone());
+    let renter = env.storage().persistent().get(&key);
+    if renter.is_some() {
+        env.storage().persistent().extend_ttl(&key, CAR_RENTER_TTL_THRESHOLD, CAR_RENTER_EXTEND_AMOUNT);
+    }
+    renter
+}
+
+pub(crate) fn write_car_renter(env: &Env, car_owner: &Address, renter: &Address) {
+    let key = DataKey::CarRenter(car_owner.clone());
+    env.storage().persistent().set(&key, renter);
+    env.storage().persistent().extend_ttl(&key, CAR_RENTER_TTL_THRESHOLD, CAR_RENTER_EXTEND_AMOUNT);
+}
+
+pub(crate) fn remove_car_renter(env: &Env, car_owner: &Address) {
+    env.storage().persistent().remove(&DataKey::CarRenter(car_owner.clone()));
+}
+
 pub(crate) fn remove_rental(env: &Env, renter: &Address, car_owner: &Address) {
     env.storage().instance().remove(&DataKey::Rental(renter.clone(), car_owner.clone()));
 }
@@ -0,0 +1,81 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{structs::rental_record::RentalRecord, types::errors::RentACarError, types::storage::DataKey};
+
+/// Owners of the cars a renter currently has out
+pub(crate) fn read_active_rentals(env: &Env, renter: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActiveRentals(renter.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub(crate) fn add_active_rental(env: &Env, renter: &Address, owner: &Address) {
+    let mut owners = read_active_rentals(env, renter);
+    if !owners.contains(owner) {
+        owners.push_back(owner.clone());
+        env.storage().persistent().set(&DataKey::ActiveRentals(renter.clone()), &owners);
+    }
+}
+
+pub(crate) fn remove_active_rental(env: &Env, renter: &Address, owner: &Address) {
+    let mut owners = read_active_rentals(env, renter);
+    if let Some(index) = owners.first_index_of(owner) {
+        owners.remove(index);
+    }
+
+    if owners.is_empty() {
+        env.storage().persistent().remove(&DataKey::ActiveRentals(renter.clone()));
+    } else {
+        env.storage().persistent().set(&DataKey::ActiveRentals(renter.clone()), &owners);
+    }
+}
+
+pub(crate) fn read_rental_history_count(env: &Env, renter: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RentalHistoryCount(renter.clone()))
+        .unwrap_or(0)
+}
+
+/// Appends a completed rental to the renter's history
+pub(crate) fn archive_rental(env: &Env, renter: &Address, record: &RentalRecord) {
+    let index = read_rental_history_count(env, renter);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RentalHistory(renter.clone(), index), record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RentalHistoryCount(renter.clone()), &(index + 1));
+}
+
+pub(crate) fn read_rental_record(env: &Env, renter: &Address, index: u32) -> Result<RentalRecord, RentACarError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RentalHistory(renter.clone(), index))
+        .ok_or(RentACarError::NotFound)
+}
+
+pub(crate) fn write_rental_record(env: &Env, renter: &Address, index: u32, record: &RentalRecord) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RentalHistory(renter.clone(), index), record);
+}
+
+/// Returns up to `limit` completed rentals starting at position `start`, oldest first
+pub(crate) fn read_rental_history(env: &Env, renter: &Address, start: u32, limit: u32) -> Vec<RentalRecord> {
+    let count = read_rental_history_count(env, renter);
+    let end = start.saturating_add(limit).min(count);
+
+    let mut records = Vec::new(env);
+    for index in start..end {
+        if let Some(record) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RentalHistory(renter.clone(), index))
+        {
+            records.push_back(record);
+        }
+    }
+    records
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+/// Rental pass contract, if the admin linked one
+pub(crate) fn read_rental_pass_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::RentalPass)
+}
+
+pub(crate) fn write_rental_pass_contract(env: &Env, pass: &Address) {
+    env.storage().instance().set(&DataKey::RentalPass, pass);
+}
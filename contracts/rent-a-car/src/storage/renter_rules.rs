@@ -0,0 +1,26 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::renter_rules::RenterRules, types::storage::DataKey};
+
+pub(crate) fn read_renter_rules(env: &Env, owner: &Address) -> RenterRules {
+    env.storage()
+        .instance()
+        .get(&DataKey::RenterRules(owner.clone()))
+        .unwrap_or(RenterRules {
+            min_rating_x100: 0,
+            min_completed_rentals: 0,
+            base_deposit: 0,
+            new_multiplier_bps: 10000,
+            bronze_multiplier_bps: 10000,
+            silver_multiplier_bps: 10000,
+            gold_multiplier_bps: 10000,
+        })
+}
+
+pub(crate) fn write_renter_rules(env: &Env, owner: &Address, rules: &RenterRules) {
+    env.storage().instance().set(&DataKey::RenterRules(owner.clone()), rules);
+}
+
+pub(crate) fn remove_renter_rules(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::RenterRules(owner.clone()));
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::{early_return::EarlyReturnPolicy, storage::DataKey};
+
+/// Whether new rentals of this car settle the owner's earnings as a stream
+pub(crate) fn read_streaming_settlement(env: &Env, owner: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::StreamingSettlement(owner.clone()))
+        .unwrap_or(false)
+}
+
+pub(crate) fn write_streaming_settlement(env: &Env, owner: &Address, enabled: bool) {
+    let key = DataKey::StreamingSettlement(owner.clone());
+    if enabled {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+pub(crate) fn read_early_return_policy(env: &Env, owner: &Address) -> EarlyReturnPolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::EarlyReturnPolicy(owner.clone()))
+        .unwrap_or(EarlyReturnPolicy::NoRefund)
+}
+
+pub(crate) fn write_early_return_policy(env: &Env, owner: &Address, policy: &EarlyReturnPolicy) {
+    env.storage().instance().set(&DataKey::EarlyReturnPolicy(owner.clone()), policy);
+}
+
+pub(crate) fn remove_early_return_policy(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::EarlyReturnPolicy(owner.clone()));
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::access_role::AccessRole;
+
+/// Access to a car given by its owner, within `valid_from..valid_until`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AccessGrant {
+    pub grantee: Address,
+    pub role: AccessRole,
+    pub valid_from: u64,
+    pub valid_until: u64,
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+use crate::storage::types::licence_class::LicenceClass;
+
+/// A verifier's statement that a driver holds a valid licence
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Attestation {
+    pub verifier: Address,
+    pub classes: Vec<LicenceClass>,  // Licence classes the driver holds
+    pub issued_at: u64,
+    pub expires_at: u64,             // Licence expiry, the attestation is void afterwards
+}
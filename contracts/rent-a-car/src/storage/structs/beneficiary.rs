@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Beneficiary {
+    pub address: Address,
+    pub share_bps: u32, // Share of rental revenue in basis points (all shares sum to 10000)
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::{contracttype, BytesN};
+
+use crate::storage::types::{appeal_status::AppealStatus, block_reason::BlockReason};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BlockRecord {
+    pub reason: BlockReason,
+    pub blocked_at: u64,
+    pub freeze_payouts: bool,   // Also withhold the address's payouts until unblocked
+}
+
+/// Appeal filed by a blocked address against its block
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Appeal {
+    pub evidence_hash: BytesN<32>,  // Hash of the statement and evidence kept off-chain
+    pub filed_at: u64,
+    pub status: AppealStatus,
+    pub resolved_at: u64,           // 0 while pending
+}
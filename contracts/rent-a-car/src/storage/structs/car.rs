@@ -15,4 +15,7 @@ pub struct Car {
     pub car_status: CarStatus,
     pub available_to_withdraw: i128,
     pub commission_percentage: i128, // Commission percentage in basis points (1% = 100, 5% = 500, etc.)
+    pub price_per_hour: i128,        // Hourly rate for partial days (0 = whole days only)
+    pub min_duration_hours: u32,     // Shortest rental accepted (0 = no minimum)
+    pub max_duration_hours: u32,     // Longest rental accepted (0 = no maximum)
 }
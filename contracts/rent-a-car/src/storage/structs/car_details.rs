@@ -0,0 +1,16 @@
+use soroban_sdk::{contracttype, BytesN, String};
+
+use crate::storage::types::{fuel_type::FuelType, transmission::Transmission};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarDetails {
+    pub year: u32,
+    pub transmission: Transmission,
+    pub fuel_type: FuelType,
+    pub mileage_km: u64,
+    pub license_plate: String,
+    pub vin: String,               // 17-character vehicle identification number, unique per registry
+    pub photo_hash: BytesN<32>,    // Content hash of the photo bundle
+    pub photo_uri: String,         // Where the photos can be fetched (e.g. ipfs://...), may be empty
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{contracttype, Address, Map, String, Vec};
+
+use crate::storage::{
+    structs::{car::Car, car_details::CarDetails},
+    types::car_status::CarStatus,
+};
+
+/// Criteria for car discovery; unset fields match every car
+#[derive(Clone)]
+#[contracttype]
+pub struct CarFilter {
+    pub statuses: Vec<CarStatus>, // Accepted statuses (empty = any)
+    pub min_passengers: u32,
+    pub ac: Option<bool>,
+    pub min_price: i128,        // Minimum price_per_day
+    pub max_price: i128,        // Maximum price_per_day (0 = no limit)
+    pub brand: Option<String>,  // Exact brand name, served from the brand index
+    pub zone: Option<u32>,      // Zone the car is currently in, served from the zone index
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CarListing {
+    pub owner: Address,
+    pub car: Car,
+}
+
+/// One page of discovery results. `next_cursor` is `None` once the index is exhausted.
+#[derive(Clone)]
+#[contracttype]
+pub struct CarPage {
+    pub cars: Vec<CarListing>,
+    pub details: Map<Address, CarDetails>, // Extended metadata of the listed cars that have it, by owner
+    pub next_cursor: Option<u32>,
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+use crate::storage::types::claim_status::ClaimStatus;
+
+/// Owner's claim for damage against a completed rental's deposit
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DamageClaim {
+    pub owner: Address,
+    pub amount: i128,                     // Amount claimed, at most the held deposit
+    pub evidence_hash: BytesN<32>,        // Owner's photos, invoices, etc. kept off-chain
+    pub renter_evidence_hash: BytesN<32>, // Renter's response when contesting (zeroes otherwise)
+    pub opened_at: u64,
+    pub status: ClaimStatus,
+    pub awarded: i128,                    // Amount paid to the owner once settled
+    pub covered: i128,                    // Part of the award paid by the insurance pool
+    pub settled_at: u64,                  // 0 while open or contested
+}
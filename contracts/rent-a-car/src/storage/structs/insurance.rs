@@ -0,0 +1,10 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Optional damage coverage sold at rental time and underwritten by the insurance pool
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct InsuranceConfig {
+    pub pool: Address,       // insurance-pool contract receiving premiums and paying claims
+    pub premium_bps: u32,    // Premium as a share of the rental's base amount (500 = 5%)
+    pub max_coverage: i128,  // Most the pool pays towards one rental's damage claim
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LoyaltyConfig {
+    pub earn_bps: u32,        // Points earned per completed rental, in basis points of the amount paid
+    pub point_value: i128,    // Discount in token units per redeemed point (0 = redemption disabled)
+}
+
+/// Summary of a renter's loyalty history
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LoyaltyAccount {
+    pub points: i128,              // Current redeemable balance
+    pub total_earned: i128,
+    pub total_redeemed: i128,
+    pub rentals_rewarded: u32,     // Completed rentals that earned points
+    pub last_earned_at: u64,       // Ledger timestamp of the last award (0 = never)
+}
@@ -1,2 +1,19 @@
+pub mod access_grant;
+pub mod attestation;
+pub mod beneficiary;
+pub mod block;
 pub mod car;
+pub mod car_details;
+pub mod car_filter;
+pub mod damage_claim;
+pub mod insurance;
+pub mod loyalty;
+pub mod price_rules;
+pub mod rating;
+pub mod promo_code;
+pub mod referral;
+pub mod renter_rules;
 pub mod rental;
+pub mod rental_options;
+pub mod rental_record;
+pub mod zone;
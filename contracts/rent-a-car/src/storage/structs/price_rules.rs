@@ -0,0 +1,32 @@
+use soroban_sdk::{contracttype, Env, Vec};
+
+/// Price adjustment for days starting inside `[start, end)`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SeasonalRate {
+    pub start: u64,
+    pub end: u64,
+    pub adjustment_bps: i32, // Positive for surcharges, negative for discounts (-10000..)
+}
+
+/// Owner-defined pricing rules evaluated for every day of a rental
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PriceRules {
+    pub weekend_surcharge_bps: u32,  // Extra charge for days starting on Saturday or Sunday (UTC)
+    pub seasons: Vec<SeasonalRate>,  // First matching season applies
+    pub weekly_discount_bps: u32,    // Discount on the total for rentals of 7 days or more
+    pub monthly_discount_bps: u32,   // Discount on the total for rentals of 30 days or more
+}
+
+impl PriceRules {
+    /// Rules that leave the car's base price untouched
+    pub fn none(env: &Env) -> Self {
+        PriceRules {
+            weekend_surcharge_bps: 0,
+            seasons: Vec::new(env),
+            weekly_discount_bps: 0,
+            monthly_discount_bps: 0,
+        }
+    }
+}
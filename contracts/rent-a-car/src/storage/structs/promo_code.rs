@@ -0,0 +1,16 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::discount::Discount;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PromoCode {
+    pub creator: Address,
+    pub car_owner: Option<Address>, // Owner-created codes only apply to that owner's car
+    pub discount: Discount,
+    pub max_uses: u32,              // 0 = unlimited
+    pub uses: u32,
+    pub per_renter_limit: u32,      // 0 = unlimited
+    pub expires_at: u64,            // Ledger timestamp after which the code is rejected (0 = never)
+    pub active: bool,
+}
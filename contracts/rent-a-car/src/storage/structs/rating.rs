@@ -0,0 +1,10 @@
+use soroban_sdk::contracttype;
+
+/// Aggregated 1-5 ratings received by an address or a car
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RatingSummary {
+    pub count: u32,
+    pub total: u32,          // Sum of all scores
+    pub average_x100: u32,   // Average score times 100 (450 = 4.5 stars), 0 when unrated
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReferralConfig {
+    pub share_bps: u32,          // Share of each rental's admin commission paid to referrers
+    pub cap_per_referrer: i128,  // Lifetime rewards a single referrer can earn (0 = unlimited)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReferralAccount {
+    pub balance: i128,       // Claimable rewards
+    pub total_earned: i128,  // Lifetime rewards, checked against the cap
+}
@@ -1,10 +1,37 @@
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Hours added by `extend_rental`, from the previous `end_time` on
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentalExtension {
+    pub start_time: u64,
+    pub end_time: u64,
+    pub amount: i128, // Base amount paid for the extension, included in the rental's amount
+}
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Rental {
-    pub total_days_to_rent: u32,
-    pub amount: i128,
+    pub holder: Address,      // Car holder when rented, earns the rental's referral and damage rewards
+    pub duration_hours: u32,  // Rented duration, whole days are 24 hours
+    pub amount: i128,         // Base amount paid, after any promo discount
     pub start_time: u64,      // Ledger timestamp when rental started
-    pub end_time: u64,        // Expected end time (start_time + days)
+    pub end_time: u64,        // Expected end time (start_time + duration)
+    pub commission: i128,     // Admin commission charged on top of amount
+    pub referral_rewards: i128, // Part of the commission credited to referrers, kept on refunds
+    pub streaming: bool,      // Owner earnings accrue linearly over start_time..end_time
+    pub claimed: i128,        // Streamed earnings already paid out to the owner
+    pub discount: i128,       // Promo code and loyalty discount taken off the base price
+    pub points_redeemed: i128, // Loyalty points burned for this rental
+    pub return_zone: Option<u32>, // Zone the car is returned in for one-way rentals
+    pub relocation_fee: i128, // One-way fee charged on top of amount, credited to the owner
+    pub deposit: i128,        // Security deposit, held through the damage claim window
+    pub premium: i128,        // Insurance premium paid to the pool (0 = not insured)
+    pub coverage: i128,       // Most the insurance pool pays towards a damage claim
+    pub insurance_pool: Option<Address>, // Pool that sold the coverage and pays claims on it
+    pub pass_contract: Option<Address>, // Contract that issued the renter's rental pass, when passes are enabled
+    pub pass_id: Option<u32>, // Rental pass issued to the renter
+    pub extensions: Vec<RentalExtension>, // Extensions in order, each priced and streamed over its own window
+    pub additional_drivers: Vec<Address>, // Extra drivers the renter added, they share the renter's access
+    pub driver_fees: i128,    // Additional driver fees charged on top of amount, credited to the owner
 }
@@ -0,0 +1,11 @@
+use soroban_sdk::{contracttype, String};
+
+/// Optional inputs to `rental`
+#[derive(Clone)]
+#[contracttype]
+pub struct RentalOptions {
+    pub promo_code: Option<String>, // Plain-text promo code, matched against stored sha256 hashes
+    pub redeem_points: i128,        // Loyalty points to burn for a discount (0 = none)
+    pub return_zone: Option<u32>,   // Zone the car will be returned in, for one-way rentals
+    pub insured: bool,              // Buy damage coverage from the insurance pool
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Archived summary of a completed rental
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentalRecord {
+    pub owner: Address,
+    pub holder: Address,         // Car holder during the rental, paid its damage award
+    pub start_time: u64,
+    pub end_time: u64,           // Scheduled end, including extensions
+    pub returned_at: u64,        // Ledger timestamp of end_rental
+    pub duration_hours: u32,
+    pub amount: i128,            // Base amount paid up front
+    pub commission: i128,        // Commission paid up front
+    pub discount: i128,
+    pub refund: i128,            // Base amount returned to the renter
+    pub commission_refund: i128, // Commission returned to the renter
+    pub deposit: i128,           // Security deposit put down for the rental
+    pub premium: i128,           // Insurance premium paid to the pool
+    pub coverage: i128,          // Insurance coverage available to a damage claim
+    pub insurance_pool: Option<Address>, // Pool that sold the coverage and pays claims on it
+    pub additional_drivers: Vec<Address>, // Extra drivers authorized on the rental
+    pub driver_fees: i128,       // Additional driver fees paid, not refunded on early return
+    pub deposit_settled: bool,   // Deposit and coverage were settled, nothing left to claim against
+    pub renter_rated: bool,      // Renter has rated the car and owner for this rental
+    pub owner_rated: bool,       // Owner has rated the renter for this rental
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::contracttype;
+
+/// Per-car requirements on renters, checked in `rental`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RenterRules {
+    pub min_rating_x100: u32,         // Minimum average renter rating times 100 (0 = none, unrated renters fail any minimum)
+    pub min_completed_rentals: u32,
+    pub base_deposit: i128,           // Security deposit before the tier multiplier (0 = no deposit)
+    pub new_multiplier_bps: u32,      // Deposit multipliers per reputation tier (10000 = 1x)
+    pub bronze_multiplier_bps: u32,
+    pub silver_multiplier_bps: u32,
+    pub gold_multiplier_bps: u32,
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, String};
+
+/// Operating area (e.g. a city) defined by the admin
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Zone {
+    pub name: String,
+    pub pickup_point: String,   // Default pickup point for cars relocated into the zone
+}
+
+/// Where a car is picked up
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarLocation {
+    pub zone_id: u32,
+    pub pickup_point: String,
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum AccessRole {
+    Driver,      // Drives the car for the owner while it is not rented
+    Maintenance, // Services the car while it is in maintenance
+}
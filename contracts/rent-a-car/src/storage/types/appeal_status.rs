@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum AppealStatus {
+    Pending,
+    Accepted,   // The block was lifted
+    Rejected,   // The block stays in place
+}
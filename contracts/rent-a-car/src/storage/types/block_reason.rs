@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum BlockReason {
+    Fraud,
+    Abuse,          // Abusive behaviour towards renters, owners or staff
+    Damage,         // Repeated or unpaid damage to cars
+    NonPayment,
+    Other,
+}
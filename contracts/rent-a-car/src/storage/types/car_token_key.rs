@@ -0,0 +1,10 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Links between listed cars and their ownership tokens. Holders, balances and approvals
+/// live in the OpenZeppelin `non_fungible` storage.
+#[derive(Clone)]
+#[contracttype]
+pub enum CarTokenKey {
+    CarToken(Address), // auto (owner con el que se listó) -> id de token
+    TokenCar(u32),     // id de token -> auto
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum ClaimStatus {
+    Open,       // Waiting for the renter to accept or contest
+    Accepted,   // Renter accepted, the owner was paid the claimed amount
+    Contested,  // Waiting for the arbiter
+    Resolved,   // Arbiter decided the awarded amount
+}
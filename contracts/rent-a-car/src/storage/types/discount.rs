@@ -0,0 +1,8 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum Discount {
+    Percent(u32),   // Basis points off the base price (1000 = 10%)
+    Fixed(i128),    // Flat amount off the base price
+}
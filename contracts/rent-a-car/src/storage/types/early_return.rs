@@ -0,0 +1,10 @@
+use soroban_sdk::contracttype;
+
+/// What a renter gets back when returning a car before `end_time`
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum EarlyReturnPolicy {
+    NoRefund,
+    ProRated,                 // Refund unused full days
+    ProRatedWithFee(u32),     // Refund unused full days minus a fee in basis points of the refund
+}
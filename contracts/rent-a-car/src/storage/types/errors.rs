@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype};
+use soroban_sdk::contracterror;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -11,15 +11,18 @@ pub enum RentACarError {
     // Validation errors
     InvalidAmount = 10,       // Amount is zero or negative
     InvalidPrice = 11,        // Price is zero or negative
-    InvalidDays = 12,         // Invalid rental days
+    InvalidDuration = 12,     // Rental duration is zero, outside the car's limits or not whole days
     AmountMismatch = 13,      // Payment amount doesn't match expected price
     InsufficientFunds = 14,   // Insufficient available funds for withdrawal
     SelfRental = 15,          // Owner trying to rent their own car
+    InvalidShares = 16,       // Beneficiary shares are empty, zero or don't sum to 10000
+    DuplicateBeneficiary = 17, // Same beneficiary listed twice
+    InvalidFee = 18,          // Fee in basis points above 10000
+    InvalidPriceRule = 19,    // Discount above 10000 bps, empty or negative season
     
     // State errors
     CarNotAvailable = 20,     // Car is not available (rented or maintenance)
-    CarAlreadyRented = 21,    // Car is already rented
-    CarStillRented = 22,      // Cannot remove car that is still rented
+    CarStillRented = 22,      // Cannot remove or transfer a car that is still rented
     RentalNotActive = 23,     // Rental is not active or doesn't exist
     
     // Authorization errors
@@ -28,6 +31,56 @@ pub enum RentACarError {
     NotOwner = 32,           // Not the owner
     
     // Contract state errors
-    AlreadyInitialized = 40,  // Contract already initialized
     NotInitialized = 41,      // Contract not initialized
+
+    // Promotion errors
+    InvalidDiscount = 50,     // Percent discount not below 100% or non-positive fixed discount
+
+    // Car metadata errors
+    InvalidCarDetails = 60,   // Year, plate, VIN, location or photo URI out of bounds
+    DuplicateVin = 61,        // VIN already registered for another car
+
+    // Driver verification errors
+    DriverNotVerified = 70,   // No attestation, or its verifier is no longer approved
+    AttestationExpired = 71,  // Licence expires before the rental ends
+    LicenceClassMismatch = 72, // Licence does not cover the class the car requires
+    NotVerifier = 73,         // Caller is not an approved verifier
+
+    // Blocklist errors
+    AddressBlocked = 80,      // Renter or owner is on the blocklist
+    PayoutsFrozen = 81,       // Payouts to the address are frozen by a block
+    NotBlocked = 82,          // Address is not on the blocklist
+    AppealPending = 83,       // An appeal is already waiting for resolution
+    NoPendingAppeal = 84,     // No appeal to resolve
+
+    // Rating errors
+    InvalidRating = 90,       // Score outside 1-5
+    RatingWindowClosed = 91,  // Rating window of the rental has passed
+    AlreadyRated = 92,        // This party already rated the rental
+
+    // Reputation errors
+    RatingTooLow = 100,       // Renter's average rating is below the car's minimum
+    NotEnoughRentals = 101,   // Renter has fewer completed rentals than the car requires
+    InvalidRenterRules = 102, // Negative deposit, multiplier above 10x or minimum rating above 5 stars
+
+    // Dispute errors
+    ClaimWindowClosed = 110,  // Damage claims must be opened within the claim window
+    ClaimWindowOpen = 111,    // Deposit cannot be released while a claim can still be opened
+    ClaimExists = 112,        // Rental already has a damage claim
+    ClaimNotPending = 113,    // Claim is not in a state that allows this action
+    ClaimExceedsDeposit = 114, // Claimed or awarded amount above the held deposit plus insurance coverage
+    NotArbiter = 115,         // Caller is not the appointed arbiter
+    DepositAlreadySettled = 116, // Deposit was already returned or paid out
+    ResponseWindowOpen = 117, // Renter can still respond to the claim
+
+    // Insurance errors
+    InsuranceNotConfigured = 120, // Coverage requested before the admin set up the insurance pool
+    InvalidInsuranceConfig = 121, // Premium above 100% or negative coverage
+
+    // Access errors
+    InvalidAccessGrant = 130, // Empty or past access window, too many grants, or granting the car holder
+
+    // Additional driver errors
+    TooManyDrivers = 140,     // Rental already has the maximum number of additional drivers
+    DuplicateDriver = 141,    // Driver is the renter or already on the rental
 }
@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum FuelType {
+    Gasoline,
+    Diesel,
+    Hybrid,
+    Electric,
+    Other,
+}
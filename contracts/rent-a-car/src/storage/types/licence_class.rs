@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+/// Driving licence categories
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum LicenceClass {
+    A,  // Motorcycles
+    B,  // Cars and light vans
+    C,  // Trucks
+    D,  // Buses and minibuses
+}
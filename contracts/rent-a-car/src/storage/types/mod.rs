@@ -1,3 +1,15 @@
+pub mod access_role;
+pub mod appeal_status;
+pub mod block_reason;
 pub mod car_status;
+pub mod car_token_key;
+pub mod claim_status;
+pub mod discount;
+pub mod early_return;
+pub mod fuel_type;
+pub mod licence_class;
+pub mod owner_registry;
+pub mod reputation_tier;
 pub mod storage;
+pub mod transmission;
 pub mod errors;
@@ -0,0 +1,10 @@
+use soroban_sdk::{contracttype, String};
+
+/// Owner registries kept as indexed sets
+#[derive(Clone)]
+#[contracttype]
+pub enum OwnerRegistry {
+    All,            // Every owner with a listed car
+    Brand(String),  // Owners whose car is of the given brand
+    Zone(u32),      // Owners whose car is currently in the given zone
+}
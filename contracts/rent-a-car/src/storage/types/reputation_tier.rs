@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+/// Renter standing derived from completed rentals and ratings received
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum ReputationTier {
+    New,      // No completed or rated rentals yet
+    Bronze,
+    Silver,   // At least 3 completed rentals averaging 4 stars
+    Gold,     // At least 10 completed rentals averaging 4.5 stars
+}
@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, String};
+
+use crate::storage::types::owner_registry::OwnerRegistry;
 
 #[derive(Clone)]
 #[contracttype]
@@ -10,5 +12,45 @@ pub enum DataKey {
     AdminCommissionBalance,      // balance acumulado de comisiones del administrador
     Car(Address),                // auto asociado a un owner
     Rental(Address, Address),    // registro de alquiler entre renter y owner
-    CarOwners,                   // lista de todos los owners que tienen autos
+    CarDetails(Address),                   // datos extendidos del auto (VIN, patente, ubicación, fotos)
+    VinOwner(String),                      // índice VIN -> owner para garantizar unicidad
+    Zone(u32),                             // zona operativa definida por el admin
+    CarLocation(Address),                  // zona y punto de retiro de un auto
+    RelocationFee(u32, u32),               // (zona origen, zona destino) -> costo de reubicación
+    KycRequired,                           // si los alquileres exigen licencia verificada
+    Verifier(Address),                     // verificadores aprobados por el admin
+    Attestation(Address),                  // licencia verificada de un conductor
+    RequiredLicence(Address),              // clase de licencia exigida por un auto
+    Blocked(Address),                      // bloqueo de un renter u owner
+    Appeal(Address),                       // apelación de una dirección bloqueada
+    RenterRating(Address),                 // calificaciones recibidas como renter
+    OwnerRating(Address),                  // calificaciones recibidas como owner
+    CarRating(Address),                    // calificaciones del auto de un owner
+    RenterRules(Address),                  // requisitos y depósito exigidos a renters por auto
+    Arbiter,                               // árbitro designado por el admin para disputas
+    DamageClaim(Address, u32),             // (renter, índice de alquiler) -> reclamo por daños
+    Insurance,                             // pool de seguros, prima y cobertura máxima
+    RentalPass,                            // contrato que emite los pases de alquiler
+    CarAccess(Address),                    // conductores y mantenimiento autorizados por el owner de un auto
+    CarRenter(Address),                    // renter que tiene alquilado un auto
+    DriverFee(Address),                    // cargo por cada conductor adicional de un alquiler
+    OwnersCount(OwnerRegistry),            // cantidad de owners en un registro
+    OwnerAt(OwnerRegistry, u32),           // (registro, posición) -> owner
+    OwnerPosition(OwnerRegistry, Address), // (registro, owner) -> posición
+    PayoutDestination(Address),  // dirección de destino de pagos de un auto (clave de listado)
+    CarBeneficiaries(Address),   // beneficiarios (co-owners) de un auto y sus participaciones
+    BeneficiaryBalance(Address, Address), // balance retirable de un beneficiario por auto
+    StreamingSettlement(Address), // si los ingresos del owner se liquidan de forma lineal
+    EarlyReturnPolicy(Address),  // política de reembolso por devolución anticipada de un auto
+    PriceRules(Address),         // reglas de precio dinámico (fin de semana, temporada, estadías largas)
+    PromoCode(BytesN<32>),       // código promocional indexado por el hash sha256 del código
+    PromoRenterUses(BytesN<32>, Address), // usos de un código promocional por renter
+    ReferralConfig,              // participación de referidos en la comisión y tope por referidor
+    Referrer(Address),           // referidor registrado por un renter u owner
+    ReferralAccount(Address),    // recompensas de un referidor (retirables y acumuladas)
+    LoyaltyConfig,               // tasas de acumulación y canje de puntos de lealtad
+    LoyaltyAccount(Address),     // puntos de lealtad e historial resumido de un renter
+    ActiveRentals(Address),      // dueños de los autos que un renter tiene alquilados
+    RentalHistory(Address, u32), // (renter, índice) -> alquiler completado
+    RentalHistoryCount(Address), // cantidad de alquileres completados de un renter
 }
@@ -0,0 +1,8 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub enum Transmission {
+    Manual,
+    Automatic,
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::zone::{CarLocation, Zone},
+    types::{errors::RentACarError, storage::DataKey},
+};
+
+pub(crate) fn has_zone(env: &Env, zone_id: u32) -> bool {
+    env.storage().instance().has(&DataKey::Zone(zone_id))
+}
+
+pub(crate) fn read_zone(env: &Env, zone_id: u32) -> Result<Zone, RentACarError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Zone(zone_id))
+        .ok_or(RentACarError::NotFound)
+}
+
+pub(crate) fn write_zone(env: &Env, zone_id: u32, zone: &Zone) {
+    env.storage().instance().set(&DataKey::Zone(zone_id), zone);
+}
+
+pub(crate) fn read_car_location(env: &Env, owner: &Address) -> Result<CarLocation, RentACarError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarLocation(owner.clone()))
+        .ok_or(RentACarError::NotFound)
+}
+
+pub(crate) fn write_car_location(env: &Env, owner: &Address, location: &CarLocation) {
+    env.storage().persistent().set(&DataKey::CarLocation(owner.clone()), location);
+}
+
+pub(crate) fn remove_car_location(env: &Env, owner: &Address) {
+    env.storage().persistent().remove(&DataKey::CarLocation(owner.clone()));
+}
+
+/// Fee for returning a car in `to_zone` after picking it up in `from_zone`; `None` when
+/// one-way rentals between the two zones are not offered
+pub(crate) fn read_relocation_fee(env: &Env, from_zone: u32, to_zone: u32) -> Option<i128> {
+    env.storage().instance().get(&DataKey::RelocationFee(from_zone, to_zone))
+}
+
+pub(crate) fn write_relocation_fee(env: &Env, from_zone: u32, to_zone: u32, fee: &i128) {
+    env.storage().instance().set(&DataKey::RelocationFee(from_zone, to_zone), fee);
+}
+
+pub(crate) fn remove_relocation_fee(env: &Env, from_zone: u32, to_zone: u32) {
+    env.storage().instance().remove(&DataKey::RelocationFee(from_zone, to_zone));
+}
@@ -0,0 +1,192 @@
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use crate::{
+    storage::{structs::beneficiary::Beneficiary, types::licence_class::LicenceClass},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+#[test]
+pub fn test_driver_fee_charged_and_credited_to_owner() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_additional_driver_fee(&owner, &150_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.add_rental_driver(&renter, &owner, &driver);
+
+    let rental = contract.get_rental(&renter, &owner);
+    assert_eq!(rental.additional_drivers, vec![&env, driver.clone()]);
+    assert_eq!(rental.driver_fees, 150);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000 - 150);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 1150);
+}
+
+#[test]
+pub fn test_drivers_share_renter_access_during_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    let rental = contract.get_rental(&renter, &owner);
+    assert!(!contract.can_access(&owner, &driver, &rental.start_time));
+
+    contract.add_rental_driver(&renter, &owner, &driver);
+    assert!(contract.can_access(&owner, &driver, &rental.start_time));
+    assert!(contract.can_access(&owner, &driver, &rental.end_time));
+
+    contract.remove_rental_driver(&renter, &owner, &driver);
+    assert!(!contract.can_access(&owner, &driver, &rental.start_time));
+    assert!(contract.can_access(&owner, &renter, &rental.start_time));
+}
+
+#[test]
+pub fn test_drivers_recorded_in_rental_history() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_additional_driver_fee(&owner, &100_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.add_rental_driver(&renter, &owner, &driver);
+    contract.end_rental(&renter, &owner);
+
+    let record = contract.get_rental_history(&renter, &0, &1).get(0).unwrap();
+    assert_eq!(record.additional_drivers, vec![&env, driver.clone()]);
+    assert_eq!(record.driver_fees, 100);
+    assert!(!contract.can_access(&owner, &driver, &env.ledger().timestamp()));
+}
+
+#[test]
+#[should_panic(expected = "DriverNotVerified")]
+pub fn test_unverified_driver_rejected_when_kyc_required() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_kyc_required(&true);
+    contract.set_verifier(&verifier, &true);
+    contract.attest_licence(&verifier, &renter, &vec![&env, LicenceClass::B], &(30 * 86_400));
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.add_rental_driver(&renter, &owner, &driver);
+}
+
+#[test]
+#[should_panic(expected = "DuplicateDriver")]
+pub fn test_duplicate_driver_rejected() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.add_rental_driver(&renter, &owner, &driver);
+    contract.add_rental_driver(&renter, &owner, &driver);
+}
+
+#[test]
+#[should_panic(expected = "TooManyDrivers")]
+pub fn test_driver_limit_enforced() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    for _ in 0..5 {
+        contract.add_rental_driver(&renter, &owner, &Address::generate(&env));
+    }
+}
+
+#[test]
+pub fn test_driver_fees_split_among_co_owners() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_additional_driver_fee(&owner, &200_i128);
+    contract.set_car_beneficiaries(&owner, &vec![
+        &env,
+        Beneficiary { address: owner.clone(), share_bps: 5000 },
+        Beneficiary { address: partner.clone(), share_bps: 5000 },
+    ]);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.add_rental_driver(&renter, &owner, &driver);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(contract.get_available_to_withdraw(&owner), 0);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &owner), 600);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &partner), 600);
+}
+
+#[test]
+#[should_panic(expected = "AttestationExpired")]
+pub fn test_extension_cannot_outlast_driver_licence() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_kyc_required(&true);
+    contract.set_verifier(&verifier, &true);
+    contract.attest_licence(&verifier, &renter, &vec![&env, LicenceClass::B], &(30 * 86_400));
+    contract.attest_licence(&verifier, &driver, &vec![&env, LicenceClass::B], &(2 * 86_400));
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.add_rental_driver(&renter, &owner, &driver);
+    contract.extend_rental(&renter, &owner, &48, &2000_i128);
+}
@@ -8,9 +8,9 @@ pub fn test_unauthorized_user_cannot_add_car() {
 
     let fake_admin = Address::generate(&env);
     let owner = Address::generate(&env);
-    let brand = String::from_slice(&env, "TestBrand");
-    let model = String::from_slice(&env, "TestModel");
-    let color = String::from_slice(&env, "Black");
+    let brand = String::from_str(&env, "TestBrand");
+    let model = String::from_str(&env, "TestModel");
+    let color = String::from_str(&env, "Black");
     let passengers = 4_u32;
     let ac = true;
     let price_per_day = 1500_i128;
@@ -0,0 +1,150 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+use crate::{
+    storage::{contract_balance::read_contract_balance, structs::beneficiary::Beneficiary},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+fn beneficiary(address: &Address, share_bps: u32) -> Beneficiary {
+    Beneficiary { address: address.clone(), share_bps }
+}
+
+fn no_beneficiaries(env: &Env) -> Vec<Beneficiary> {
+    Vec::new(env)
+}
+
+#[test]
+pub fn test_revenue_split_among_beneficiaries_on_end_rental() {
+    let ContractTest { env, contract, token, address, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let investor_a = Address::generate(&env);
+    let investor_b = Address::generate(&env);
+    let investor_c = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.set_car_beneficiaries(&owner, &vec![
+        &env,
+        beneficiary(&investor_a, 3334),
+        beneficiary(&investor_b, 3333),
+        beneficiary(&investor_c, 3333),
+    ]);
+
+    // Revenue stays on the car while it is rented
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    assert_eq!(contract.get_beneficiary_balance(&owner, &investor_a), 0);
+
+    contract.end_rental(&renter, &owner);
+
+    // 1000 * 3333 / 10000 = 333 each for B and C, A takes the remaining 334
+    assert_eq!(contract.get_beneficiary_balance(&owner, &investor_a), 334);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &investor_b), 333);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &investor_c), 333);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 0);
+
+    assert_eq!(contract.withdraw_beneficiary_share(&investor_b, &owner), 333);
+    assert_eq!(token_client.balance(&investor_b), 333);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &investor_b), 0);
+
+    let contract_balance = env.as_contract(&address, || read_contract_balance(&env));
+    assert_eq!(contract_balance, 667);
+}
+
+#[test]
+pub fn test_clearing_beneficiaries_restores_single_owner() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.set_car_beneficiaries(&owner, &vec![&env, beneficiary(&owner, 5000), beneficiary(&investor, 5000)]);
+    contract.set_car_beneficiaries(&owner, &no_beneficiaries(&env));
+    assert!(contract.get_car_beneficiaries(&owner).is_empty());
+
+    contract.rental(&renter, &owner, &48, &2000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 2000);
+}
+
+#[test]
+#[should_panic(expected = "InvalidShares")]
+pub fn test_beneficiary_shares_must_sum_to_10000() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.set_car_beneficiaries(&owner, &vec![&env, beneficiary(&owner, 5000), beneficiary(&investor, 4000)]);
+}
+
+#[test]
+#[should_panic(expected = "DuplicateBeneficiary")]
+pub fn test_beneficiaries_must_be_unique() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.set_car_beneficiaries(&owner, &vec![&env, beneficiary(&owner, 5000), beneficiary(&owner, 5000)]);
+}
+
+#[test]
+#[should_panic(expected = "Cannot change beneficiaries while car is rented")]
+pub fn test_beneficiaries_locked_while_rented() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+
+    contract.set_car_beneficiaries(&owner, &vec![&env, beneficiary(&investor, 10000)]);
+}
+
+#[test]
+pub fn test_car_payout_destination_does_not_redirect_co_owner_share() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let mallory = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &alice, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &bob, 1000_i128, 0_i128);
+    contract.set_car_beneficiaries(&bob, &vec![&env, beneficiary(&bob, 5000), beneficiary(&alice, 5000)]);
+
+    // Alice sells her car, the buyer points that car's payouts at themselves
+    contract.transfer(&alice, &mallory, &contract.get_car_token(&alice));
+    contract.set_payout_destination(&alice, &mallory);
+
+    contract.rental(&renter, &bob, &48, &2000_i128, &no_options());
+    contract.end_rental(&renter, &bob);
+
+    assert_eq!(contract.withdraw_beneficiary_share(&alice, &bob), 1000);
+    assert_eq!(token_client.balance(&alice), 1000);
+    assert_eq!(token_client.balance(&mallory), 0);
+}
@@ -0,0 +1,250 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN, Vec};
+use crate::{
+    methods::dispute::CLAIM_WINDOW_SECONDS,
+    storage::{
+        structs::{car_filter::CarFilter, renter_rules::RenterRules},
+        types::{appeal_status::AppealStatus, block_reason::BlockReason, early_return::EarlyReturnPolicy},
+    },
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+#[test]
+#[should_panic(expected = "AddressBlocked")]
+pub fn test_blocked_renter_cannot_rent() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.block_address(&renter, &BlockReason::NonPayment, &false);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "AddressBlocked")]
+pub fn test_blocked_owner_cannot_list_a_car() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.block_address(&owner, &BlockReason::Fraud, &false);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+}
+
+#[test]
+pub fn test_blocked_owner_car_is_delisted() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let filter = CarFilter {
+        statuses: Vec::new(&env),
+        min_passengers: 0,
+        ac: None,
+        min_price: 0,
+        max_price: 0,
+        brand: None,
+        zone: None,
+    };
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.block_address(&owner, &BlockReason::Fraud, &false);
+    assert!(contract.list_cars(&filter, &0, &10).cars.is_empty());
+
+    contract.unblock_address(&owner);
+    assert_eq!(contract.list_cars(&filter, &0, &10).cars.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "PayoutsFrozen")]
+pub fn test_freezing_block_withholds_payouts() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+
+    contract.block_address(&owner, &BlockReason::Fraud, &true);
+    contract.payout_owner(&owner, &1000_i128);
+}
+
+#[test]
+pub fn test_batch_payout_skips_frozen_owners() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let frozen = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &frozen, 1000_i128, 0_i128);
+    contract.rental(&renter, &frozen, &24, &1000_i128, &no_options());
+    contract.end_rental(&renter, &frozen);
+    contract.block_address(&frozen, &BlockReason::Damage, &true);
+
+    let owners = Vec::from_array(&env, [frozen.clone()]);
+    assert_eq!(contract.batch_payout(&owners), 0);
+    assert_eq!(token_client.balance(&frozen), 0);
+    assert_eq!(contract.get_available_to_withdraw(&frozen), 1000);
+}
+
+#[test]
+pub fn test_accepted_appeal_lifts_block() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let evidence = BytesN::from_array(&env, &[1; 32]);
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    contract.block_address(&renter, &BlockReason::Abuse, &false);
+    assert_eq!(contract.get_block(&renter).blocked_at, 1_000);
+
+    env.ledger().set_timestamp(2_000);
+    contract.file_appeal(&renter, &evidence);
+    assert_eq!(contract.get_appeal(&renter).status, AppealStatus::Pending);
+
+    env.ledger().set_timestamp(3_000);
+    contract.resolve_appeal(&renter, &true);
+
+    let appeal = contract.get_appeal(&renter);
+    assert_eq!(appeal.status, AppealStatus::Accepted);
+    assert_eq!(appeal.filed_at, 2_000);
+    assert_eq!(appeal.resolved_at, 3_000);
+    assert!(!contract.is_blocked(&renter));
+}
+
+#[test]
+pub fn test_rejected_appeal_keeps_block_and_allows_new_appeal() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.block_address(&renter, &BlockReason::Abuse, &false);
+    contract.file_appeal(&renter, &BytesN::from_array(&env, &[1; 32]));
+    contract.resolve_appeal(&renter, &false);
+
+    assert!(contract.is_blocked(&renter));
+    assert_eq!(contract.get_appeal(&renter).status, AppealStatus::Rejected);
+
+    contract.file_appeal(&renter, &BytesN::from_array(&env, &[2; 32]));
+    assert_eq!(contract.get_appeal(&renter).status, AppealStatus::Pending);
+}
+
+#[test]
+#[should_panic(expected = "AppealPending")]
+pub fn test_only_one_pending_appeal() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.block_address(&renter, &BlockReason::Other, &false);
+    contract.file_appeal(&renter, &BytesN::from_array(&env, &[1; 32]));
+    contract.file_appeal(&renter, &BytesN::from_array(&env, &[2; 32]));
+}
+
+#[test]
+pub fn test_unblock_accepts_pending_appeal() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.block_address(&renter, &BlockReason::Abuse, &false);
+    contract.file_appeal(&renter, &BytesN::from_array(&env, &[1; 32]));
+
+    env.ledger().set_timestamp(5_000);
+    contract.unblock_address(&renter);
+
+    let appeal = contract.get_appeal(&renter);
+    assert_eq!(appeal.status, AppealStatus::Accepted);
+    assert_eq!(appeal.resolved_at, 5_000);
+
+    // A later block starts a fresh appeal
+    contract.block_address(&renter, &BlockReason::Abuse, &false);
+    contract.file_appeal(&renter, &BytesN::from_array(&env, &[2; 32]));
+    assert_eq!(contract.get_appeal(&renter).status, AppealStatus::Pending);
+}
+
+#[test]
+#[should_panic(expected = "PayoutsFrozen")]
+pub fn test_frozen_renter_deposit_stays_held() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_renter_rules(&owner, &RenterRules {
+        min_rating_x100: 0,
+        min_completed_rentals: 0,
+        base_deposit: 500,
+        new_multiplier_bps: 10000,
+        bronze_multiplier_bps: 10000,
+        silver_multiplier_bps: 10000,
+        gold_multiplier_bps: 10000,
+    });
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+
+    contract.block_address(&renter, &BlockReason::NonPayment, &true);
+    env.ledger().with_mut(|li| li.timestamp += CLAIM_WINDOW_SECONDS + 1);
+    contract.release_deposit(&renter, &0);
+}
+
+#[test]
+#[should_panic(expected = "PayoutsFrozen")]
+pub fn test_frozen_renter_gets_no_early_return_refund() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_early_return_policy(&owner, &EarlyReturnPolicy::ProRated);
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+
+    contract.block_address(&renter, &BlockReason::Fraud, &true);
+    contract.end_rental(&renter, &owner);
+}
+
+#[test]
+pub fn test_frozen_renter_can_end_rental_without_refund() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+
+    contract.block_address(&renter, &BlockReason::Fraud, &true);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(token_client.balance(&renter), 7_000);
+    assert!(!contract.has_rental(&renter, &owner));
+}
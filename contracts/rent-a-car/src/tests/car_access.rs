@@ -0,0 +1,251 @@
+use soroban_sdk::{testutils::{storage::Persistent as _, Address as _, Ledger}, vec, Address};
+use crate::{
+    storage::{rental::CAR_RENTER_EXTEND_AMOUNT, types::{access_role::AccessRole, car_status::CarStatus, storage::DataKey}},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+const DAY: u64 = 24 * 60 * 60;
+
+#[test]
+pub fn test_renter_has_access_only_during_rental_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 10 * DAY);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    let rental = contract.get_rental(&renter, &owner);
+
+    assert!(contract.can_access(&owner, &renter, &rental.start_time));
+    assert!(contract.can_access(&owner, &renter, &rental.end_time));
+    assert!(!contract.can_access(&owner, &renter, &(rental.start_time - 1)));
+
+    // The owner is only locked out for the rental window itself
+    assert!(!contract.can_access(&owner, &owner, &rental.end_time));
+    assert!(contract.can_access(&owner, &owner, &(rental.start_time - 1)));
+    assert!(contract.can_access(&owner, &owner, &(rental.end_time + 1)));
+}
+
+#[test]
+pub fn test_overdue_renter_keeps_access_until_return() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    let rental = contract.get_rental(&renter, &owner);
+
+    env.ledger().with_mut(|li| li.timestamp = rental.end_time + DAY);
+    let now = env.ledger().timestamp();
+    assert!(contract.can_access(&owner, &renter, &now));
+
+    contract.end_rental(&renter, &owner);
+    assert!(!contract.can_access(&owner, &renter, &now));
+    assert!(contract.can_access(&owner, &owner, &now));
+}
+
+#[test]
+pub fn test_owner_locked_out_while_car_is_rented() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    let now = env.ledger().timestamp();
+
+    assert!(contract.can_access(&owner, &owner, &now));
+    assert!(!contract.can_access(&owner, &stranger, &now));
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    assert!(!contract.can_access(&owner, &owner, &now));
+
+    contract.end_rental(&renter, &owner);
+    assert!(contract.can_access(&owner, &owner, &now));
+    assert!(!contract.can_access(&owner, &renter, &now));
+}
+
+#[test]
+pub fn test_driver_grant_follows_window_and_revocation() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let driver = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    let now = env.ledger().timestamp();
+
+    contract.grant_car_access(&owner, &driver, &AccessRole::Driver, &now, &(now + DAY));
+    assert_eq!(contract.get_car_access_grants(&owner).len(), 1);
+    assert!(contract.can_access(&owner, &driver, &now));
+    assert!(!contract.can_access(&owner, &driver, &(now + DAY)));
+
+    contract.revoke_car_access(&owner, &driver);
+    assert_eq!(contract.get_car_access_grants(&owner).len(), 0);
+    assert!(!contract.can_access(&owner, &driver, &now));
+}
+
+#[test]
+pub fn test_maintenance_grant_requires_maintenance_status() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let mechanic = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    let now = env.ledger().timestamp();
+
+    contract.grant_car_access(&owner, &mechanic, &AccessRole::Maintenance, &now, &(now + DAY));
+    assert!(!contract.can_access(&owner, &mechanic, &now));
+
+    contract.set_car_maintenance(&owner, &true);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Maintenance);
+    assert!(contract.can_access(&owner, &mechanic, &now));
+    assert!(contract.can_access(&owner, &owner, &now));
+
+    contract.set_car_maintenance(&owner, &false);
+    assert!(!contract.can_access(&owner, &mechanic, &now));
+}
+
+#[test]
+#[should_panic(expected = "Car is not available")]
+pub fn test_car_in_maintenance_cannot_be_rented() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.set_car_maintenance(&owner, &true);
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+}
+
+#[test]
+pub fn test_grants_cleared_on_token_transfer() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let driver = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    let now = env.ledger().timestamp();
+
+    contract.grant_car_access(&owner, &driver, &AccessRole::Driver, &now, &(now + DAY));
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+
+    assert_eq!(contract.get_car_access_grants(&owner).len(), 0);
+    assert!(!contract.can_access(&owner, &driver, &now));
+    assert!(!contract.can_access(&owner, &owner, &now));
+    assert!(contract.can_access(&owner, &buyer, &now));
+}
+
+#[test]
+#[should_panic(expected = "InvalidAccessGrant")]
+pub fn test_grant_with_empty_window_rejected() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let driver = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    let now = env.ledger().timestamp();
+
+    contract.grant_car_access(&owner, &driver, &AccessRole::Driver, &(now + DAY), &(now + DAY));
+}
+
+#[test]
+pub fn test_owner_can_withdraw_while_in_maintenance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &48, &2000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+    contract.set_car_maintenance(&owner, &true);
+
+    contract.payout_owner(&owner, &500_i128);
+    assert_eq!(token_client.balance(&owner), 500);
+    assert_eq!(contract.batch_payout(&vec![&env, owner.clone()]), 1500);
+    assert_eq!(token_client.balance(&owner), 2000);
+}
+
+#[test]
+pub fn test_token_transfer_pays_seller_while_in_maintenance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+    contract.set_car_maintenance(&owner, &true);
+
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+
+    assert_eq!(token_client.balance(&owner), 1000);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 0);
+    assert_eq!(contract.owner_of(&contract.get_car_token(&owner)), buyer);
+}
+
+#[test]
+pub fn test_car_renter_kept_in_persistent_storage() {
+    let ContractTest { env, contract, token, address, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    env.as_contract(&address, || {
+        let key = DataKey::CarRenter(owner.clone());
+        assert_eq!(env.storage().persistent().get::<_, Address>(&key), Some(renter.clone()));
+        assert!(env.storage().persistent().get_ttl(&key) >= CAR_RENTER_EXTEND_AMOUNT);
+        assert!(!env.storage().instance().has(&key));
+    });
+
+    contract.end_rental(&renter, &owner);
+    env.as_contract(&address, || {
+        assert!(!env.storage().persistent().has(&DataKey::CarRenter(owner.clone())));
+    });
+}
@@ -0,0 +1,132 @@
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Vec};
+use crate::{
+    storage::{
+        structs::{car_details::CarDetails, car_filter::CarFilter},
+        types::{fuel_type::FuelType, transmission::Transmission},
+    },
+    tests::config::{contract::ContractTest, utils::add_test_car},
+};
+
+fn test_details(env: &Env, vin: &str) -> CarDetails {
+    CarDetails {
+        year: 2021,
+        transmission: Transmission::Automatic,
+        fuel_type: FuelType::Hybrid,
+        mileage_km: 42_000,
+        license_plate: String::from_str(env, "AB123CD"),
+        vin: String::from_str(env, vin),
+        photo_hash: BytesN::from_array(env, &[7; 32]),
+        photo_uri: String::from_str(env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+    }
+}
+
+#[test]
+pub fn test_owner_publishes_car_details() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    let details = test_details(&env, "1HGCM82633A004352");
+    contract.set_car_details(&owner, &details);
+
+    assert_eq!(contract.get_car_details(&owner), details);
+    assert_eq!(contract.get_car_by_vin(&details.vin), owner);
+}
+
+#[test]
+pub fn test_changing_vin_frees_the_previous_one() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &other_owner, 1000_i128, 0_i128);
+
+    contract.set_car_details(&owner, &test_details(&env, "1HGCM82633A004352"));
+    contract.set_car_details(&owner, &test_details(&env, "5YJ3E1EA7KF317000"));
+
+    // The corrected VIN is now free for the car it actually belongs to
+    contract.set_car_details(&other_owner, &test_details(&env, "1HGCM82633A004352"));
+    assert_eq!(contract.get_car_by_vin(&String::from_str(&env, "1HGCM82633A004352")), other_owner);
+}
+
+#[test]
+#[should_panic(expected = "DuplicateVin")]
+pub fn test_vin_must_be_unique() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &other_owner, 1000_i128, 0_i128);
+
+    contract.set_car_details(&owner, &test_details(&env, "1HGCM82633A004352"));
+    contract.set_car_details(&other_owner, &test_details(&env, "1HGCM82633A004352"));
+}
+
+#[test]
+#[should_panic(expected = "InvalidCarDetails")]
+pub fn test_vin_rejects_ambiguous_characters() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.set_car_details(&owner, &test_details(&env, "1HGCM82633O004352"));
+}
+
+#[test]
+pub fn test_removing_car_releases_vin() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_car_details(&owner, &test_details(&env, "1HGCM82633A004352"));
+    contract.remove_car(&owner);
+
+    // The car is listed again under its new owner
+    add_test_car(&env, &contract, &new_owner, 1000_i128, 0_i128);
+    contract.set_car_details(&new_owner, &test_details(&env, "1HGCM82633A004352"));
+    assert_eq!(contract.get_car_by_vin(&String::from_str(&env, "1HGCM82633A004352")), new_owner);
+}
+
+#[test]
+pub fn test_list_cars_includes_details() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let bare_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &bare_owner, 1000_i128, 0_i128);
+    let details = test_details(&env, "1HGCM82633A004352");
+    contract.set_car_details(&owner, &details);
+
+    let filter = CarFilter {
+        statuses: Vec::new(&env),
+        min_passengers: 0,
+        ac: None,
+        min_price: 0,
+        max_price: 0,
+        brand: None,
+        zone: None,
+    };
+    let page = contract.list_cars(&filter, &0, &10);
+
+    assert_eq!(page.cars.len(), 2);
+    assert_eq!(page.details.get(owner), Some(details));
+    assert_eq!(page.details.get(bare_owner), None);
+}
@@ -0,0 +1,127 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+use crate::{
+    contract::RentACarContractClient,
+    methods::discovery::MAX_SCAN,
+    storage::{structs::car_filter::CarFilter, types::car_status::CarStatus},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+fn any_car(env: &Env) -> CarFilter {
+    CarFilter {
+        statuses: Vec::new(env),
+        min_passengers: 0,
+        ac: None,
+        min_price: 0,
+        max_price: 0,
+        brand: None,
+        zone: None,
+    }
+}
+
+fn add_branded_car(env: &Env, contract: &RentACarContractClient, owner: &Address, brand: &str, passengers: u32, ac: bool, price_per_day: i128) {
+    let brand = String::from_str(env, brand);
+    let model = String::from_str(env, "Model");
+    let color = String::from_str(env, "White");
+    contract.add_car(owner, &brand, &model, &color, &passengers, &ac, &price_per_day, &0_i128);
+}
+
+#[test]
+pub fn test_list_cars_applies_filters() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let sedan = Address::generate(&env);
+    let van = Address::generate(&env);
+    let cheap = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_branded_car(&env, &contract, &sedan, "Toyota", 5, true, 3000);
+    add_branded_car(&env, &contract, &van, "Ford", 8, true, 5000);
+    add_branded_car(&env, &contract, &cheap, "Toyota", 4, false, 1000);
+    contract.rental(&renter, &sedan, &24, &3000_i128, &no_options());
+
+    let page = contract.list_cars(&any_car(&env), &0, &10);
+    assert_eq!(page.cars.len(), 3);
+    assert_eq!(page.next_cursor, None);
+
+    let mut filter = any_car(&env);
+    filter.statuses = vec![&env, CarStatus::Available];
+    filter.ac = Some(true);
+    let page = contract.list_cars(&filter, &0, &10);
+    assert_eq!(page.cars.len(), 1);
+    assert_eq!(page.cars.get(0).unwrap().owner, van);
+
+    let mut filter = any_car(&env);
+    filter.min_passengers = 5;
+    filter.max_price = 4000;
+    let page = contract.list_cars(&filter, &0, &10);
+    assert_eq!(page.cars.len(), 1);
+    assert_eq!(page.cars.get(0).unwrap().owner, sedan);
+
+    let mut filter = any_car(&env);
+    filter.brand = Some(String::from_str(&env, "Toyota"));
+    filter.min_price = 2000;
+    let page = contract.list_cars(&filter, &0, &10);
+    assert_eq!(page.cars.len(), 1);
+    assert_eq!(page.cars.get(0).unwrap().car.price_per_day, 3000);
+}
+
+#[test]
+pub fn test_list_cars_pages_with_cursor() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+    for _ in 0..5 {
+        add_test_car(&env, &contract, &Address::generate(&env), 1000_i128, 0_i128);
+    }
+
+    let first = contract.list_cars(&any_car(&env), &0, &2);
+    assert_eq!(first.cars.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+
+    let second = contract.list_cars(&any_car(&env), &2, &2);
+    assert_eq!(second.next_cursor, Some(4));
+
+    let last = contract.list_cars(&any_car(&env), &4, &2);
+    assert_eq!(last.cars.len(), 1);
+    assert_eq!(last.next_cursor, None);
+}
+
+#[test]
+pub fn test_list_cars_bounds_scan_per_call() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+    for _ in 0..MAX_SCAN + 5 {
+        add_test_car(&env, &contract, &Address::generate(&env), 1000_i128, 0_i128);
+    }
+
+    // No car matches, so the call stops after MAX_SCAN entries and hands back a cursor
+    let mut filter = any_car(&env);
+    filter.min_passengers = 10;
+    let page = contract.list_cars(&filter, &0, &10);
+    assert!(page.cars.is_empty());
+    assert_eq!(page.next_cursor, Some(MAX_SCAN));
+
+    let page = contract.list_cars(&filter, &MAX_SCAN, &10);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+pub fn test_brand_index_follows_car_removal() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_branded_car(&env, &contract, &owner, "Tesla", 5, true, 4000);
+
+    let mut filter = any_car(&env);
+    filter.brand = Some(String::from_str(&env, "Tesla"));
+    assert_eq!(contract.list_cars(&filter, &0, &10).cars.len(), 1);
+
+    contract.remove_car(&owner);
+    assert!(contract.list_cars(&filter, &0, &10).cars.is_empty());
+}
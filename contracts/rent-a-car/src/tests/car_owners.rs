@@ -0,0 +1,91 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use crate::{
+    storage::{
+        car_owners::{add_car_owner, add_owner, get_car_owners, get_car_owners_count, remove_car_owner},
+        types::owner_registry::OwnerRegistry,
+    },
+    tests::config::{contract::ContractTest, utils::add_test_car},
+};
+
+#[test]
+pub fn test_removing_an_owner_swaps_in_the_last_one() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &first, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &second, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &third, 1000_i128, 0_i128);
+    assert_eq!(contract.get_car_owners_count(), 3);
+
+    contract.remove_car(&first);
+
+    let owners = contract.get_all_car_owners();
+    assert_eq!(contract.get_car_owners_count(), 2);
+    assert_eq!(owners.get(0), Some(third.clone()));
+    assert_eq!(owners.get(1), Some(second.clone()));
+
+    contract.remove_car(&third);
+    contract.remove_car(&second);
+    assert_eq!(contract.get_car_owners_count(), 0);
+    assert!(contract.get_all_car_owners().is_empty());
+}
+
+#[test]
+pub fn test_adding_an_owner_twice_keeps_one_entry() {
+    let ContractTest { env, address, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    env.as_contract(&address, || {
+        add_car_owner(&env, &owner);
+        add_car_owner(&env, &owner);
+        assert_eq!(get_car_owners_count(&env), 1);
+        assert_eq!(get_car_owners(&env).len(), 1);
+    });
+}
+
+const TOTAL_OWNERS: u32 = 300;
+
+/// CPU instructions spent adding and then removing one owner from a registry of `size` owners.
+/// The remaining owners go to another registry so the ledger holds the same number of entries
+/// in every run and only the registry size changes.
+fn add_remove_cost(env: &Env, contract: &Address, size: u32) -> u64 {
+    env.cost_estimate().budget().reset_unlimited();
+    env.as_contract(contract, || {
+        let filler = OwnerRegistry::Brand(String::from_str(env, "Filler"));
+        for _ in size..TOTAL_OWNERS {
+            add_owner(env, &filler, &Address::generate(env));
+        }
+
+        let first = Address::generate(env);
+        add_car_owner(env, &first);
+        for _ in 1..size {
+            add_car_owner(env, &Address::generate(env));
+        }
+
+        let owner = Address::generate(env);
+
+        env.cost_estimate().budget().reset_default();
+        add_car_owner(env, &owner);
+        remove_car_owner(env, &first);
+        env.cost_estimate().budget().cpu_instruction_cost()
+    })
+}
+
+#[test]
+pub fn test_registry_cost_does_not_grow_with_owners() {
+    let small = {
+        let ContractTest { env, address, .. } = ContractTest::setup();
+        add_remove_cost(&env, &address, 10)
+    };
+    let large = {
+        let ContractTest { env, address, .. } = ContractTest::setup();
+        add_remove_cost(&env, &address, TOTAL_OWNERS)
+    };
+
+    // A linear registry would cost ~30x more here; allow some noise for storage map lookups
+    assert!(large < small + small / 10, "registry cost grew from {} to {}", small, large);
+}
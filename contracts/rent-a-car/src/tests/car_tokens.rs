@@ -0,0 +1,226 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, String};
+use crate::{
+    storage::structs::beneficiary::Beneficiary,
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+#[test]
+pub fn test_listing_a_car_mints_its_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &other_owner, 1000_i128, 0_i128);
+
+    assert_eq!(contract.get_car_token(&owner), 0);
+    assert_eq!(contract.get_car_token(&other_owner), 1);
+    assert_eq!(contract.get_token_car(&1), other_owner);
+    assert_eq!(contract.owner_of(&0), owner);
+    assert_eq!(contract.balance(&owner), 1);
+    assert_eq!(contract.name(), String::from_str(&env, "Stellar Motors Car"));
+    assert_eq!(contract.symbol(), String::from_str(&env, "SMCAR"));
+}
+
+#[test]
+pub fn test_transfer_hands_over_listing_and_future_earnings() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+
+    // Earnings accrued before the sale go to the seller
+    contract.transfer(&owner, &buyer, &0);
+    assert_eq!(token_client.balance(&owner), 1000);
+    assert_eq!(contract.owner_of(&0), buyer);
+    assert_eq!(contract.balance(&owner), 0);
+    assert_eq!(contract.balance(&buyer), 1);
+
+    // The car keeps its listing key, the buyer now manages it and collects
+    contract.set_car_pricing(&owner, &50_i128, &1_u32, &0_u32);
+    assert_eq!(env.auths()[0].0, buyer);
+    assert_eq!(contract.get_payout_destination(&owner), buyer);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+    contract.payout_all(&owner);
+    assert_eq!(env.auths()[0].0, buyer);
+    assert_eq!(token_client.balance(&buyer), 1000);
+    assert_eq!(token_client.balance(&owner), 1000);
+}
+
+#[test]
+pub fn test_transfer_resets_payout_setup() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let vault = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_payout_destination(&owner, &vault);
+    contract.set_car_beneficiaries(&owner, &vec![
+        &env,
+        Beneficiary { address: owner.clone(), share_bps: 5000 },
+        Beneficiary { address: partner.clone(), share_bps: 5000 },
+    ]);
+
+    contract.transfer(&owner, &buyer, &0);
+
+    assert_eq!(contract.get_payout_destination(&owner), buyer);
+    assert_eq!(contract.get_car_beneficiaries(&owner).len(), 0);
+}
+
+#[test]
+pub fn test_approved_spender_transfers() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    let live_until = env.ledger().sequence() + 100;
+    contract.approve(&owner, &marketplace, &0, &live_until);
+    assert_eq!(contract.get_approved(&0), Some(marketplace.clone()));
+
+    contract.transfer_from(&marketplace, &owner, &buyer, &0);
+    assert_eq!(contract.owner_of(&0), buyer);
+    // Approvals do not survive a transfer
+    assert_eq!(contract.get_approved(&0), None);
+}
+
+#[test]
+pub fn test_operator_transfers_all_tokens() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.approve_for_all(&owner, &operator, &(env.ledger().sequence() + 100));
+    assert!(contract.is_approved_for_all(&owner, &operator));
+
+    contract.transfer_from(&operator, &owner, &buyer, &0);
+    assert_eq!(contract.owner_of(&0), buyer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")] // NonFungibleTokenError::InsufficientApproval
+pub fn test_expired_approval_cannot_transfer() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.approve(&owner, &marketplace, &0, &(env.ledger().sequence() + 10));
+    env.ledger().with_mut(|li| li.sequence_number += 11);
+
+    contract.transfer_from(&marketplace, &owner, &buyer, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")] // NonFungibleTokenError::IncorrectOwner
+pub fn test_only_holder_transfers() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.transfer(&stranger, &stranger, &0);
+}
+
+#[test]
+#[should_panic(expected = "CarStillRented")]
+pub fn test_rented_car_cannot_be_transferred() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+
+    contract.transfer(&owner, &buyer, &0);
+}
+
+#[test]
+#[should_panic(expected = "SelfRental")]
+pub fn test_holder_cannot_rent_own_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&buyer, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.transfer(&owner, &buyer, &0);
+
+    contract.rental(&buyer, &owner, &24, &1000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")] // NonFungibleTokenError::NonExistentToken
+pub fn test_removing_car_burns_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.remove_car(&owner);
+
+    assert_eq!(contract.balance(&owner), 0);
+    contract.owner_of(&0);
+}
+
+#[test]
+pub fn test_re_adding_car_keeps_its_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    let token_id = contract.get_car_token(&owner);
+    contract.transfer(&owner, &buyer, &token_id);
+
+    add_test_car(&env, &contract, &owner, 1200_i128, 0_i128);
+
+    assert_eq!(contract.get_car_token(&owner), token_id);
+    assert_eq!(contract.owner_of(&token_id), buyer);
+    assert_eq!(contract.balance(&owner), 0);
+    assert_eq!(contract.balance(&buyer), 1);
+}
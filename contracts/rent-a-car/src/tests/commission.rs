@@ -4,7 +4,7 @@ use crate::{
         contract_balance::read_contract_balance,
         car::read_car,
     },
-    tests::config::contract::ContractTest,
+    tests::config::{contract::ContractTest, utils::no_options},
 };
 
 #[test]
@@ -15,7 +15,7 @@ pub fn test_rental_with_percentage_commission() {
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
     let commission_percentage = 500_i128; // 5% commission
-    let total_days = 3;
+    let duration_hours = 72; // 3 days
     let amount = 4500_i128;
     
     let (_, token_admin, _) = token;
@@ -24,9 +24,9 @@ pub fn test_rental_with_percentage_commission() {
     env.mock_all_auths();
     token_admin.mint(&renter, &amount_mint);
 
-    let brand = soroban_sdk::String::from_slice(&env, "TestBrand");
-    let model = soroban_sdk::String::from_slice(&env, "TestModel");
-    let color = soroban_sdk::String::from_slice(&env, "Black");
+    let brand = soroban_sdk::String::from_str(&env, "TestBrand");
+    let model = soroban_sdk::String::from_str(&env, "TestModel");
+    let color = soroban_sdk::String::from_str(&env, "Black");
     let passengers = 4_u32;
     let ac = true;
 
@@ -38,7 +38,7 @@ pub fn test_rental_with_percentage_commission() {
     let total_expected = amount + expected_commission;
 
     env.mock_all_auths();
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &owner, &duration_hours, &amount, &no_options());
 
     // Verificar que el balance del contrato incluye depósito + comisión
     let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
@@ -62,7 +62,7 @@ pub fn test_withdraw_admin_commissions() {
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
     let commission_percentage = 500_i128; // 5% commission
-    let total_days = 3;
+    let duration_hours = 72; // 3 days
     let amount = 4500_i128;
     
     let (token_client, token_admin, _) = token;
@@ -71,9 +71,9 @@ pub fn test_withdraw_admin_commissions() {
     env.mock_all_auths();
     token_admin.mint(&renter, &amount_mint);
 
-    let brand = soroban_sdk::String::from_slice(&env, "TestBrand");
-    let model = soroban_sdk::String::from_slice(&env, "TestModel");
-    let color = soroban_sdk::String::from_slice(&env, "Black");
+    let brand = soroban_sdk::String::from_str(&env, "TestBrand");
+    let model = soroban_sdk::String::from_str(&env, "TestModel");
+    let color = soroban_sdk::String::from_str(&env, "Black");
     let passengers = 4_u32;
     let ac = true;
 
@@ -81,7 +81,7 @@ pub fn test_withdraw_admin_commissions() {
     contract.add_car(&owner, &brand, &model, &color, &passengers, &ac, &price_per_day, &commission_percentage);
 
     env.mock_all_auths();
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &owner, &duration_hours, &amount, &no_options());
 
     // Calculate expected commission: 5% of 4500 = 225
     let expected_commission = (amount * commission_percentage) / 10000_i128;
@@ -1,4 +1,7 @@
-use soroban_sdk::{token, Address, Env};
+use soroban_sdk::{token, Address, Env, String};
+
+use crate::contract::RentACarContractClient;
+use crate::storage::structs::rental_options::RentalOptions;
 
 pub(crate) fn create_token_contract<'a>(
     e: &Env,
@@ -10,4 +13,24 @@ pub(crate) fn create_token_contract<'a>(
         token::Client::new(e, &addr.address()),
         token::StellarAssetClient::new(e, &addr.address()),
     )
-}
\ No newline at end of file
+}
+
+/// Lists a 4-seat test car for `owner` (auths must already be mocked)
+pub(crate) fn add_test_car(
+    env: &Env,
+    contract: &RentACarContractClient,
+    owner: &Address,
+    price_per_day: i128,
+    commission_percentage: i128,
+) {
+    let brand = String::from_str(env, "TestBrand");
+    let model = String::from_str(env, "TestModel");
+    let color = String::from_str(env, "Black");
+
+    contract.add_car(owner, &brand, &model, &color, &4_u32, &true, &price_per_day, &commission_percentage);
+}
+
+/// Rental options with no promo code or extras
+pub(crate) fn no_options() -> RentalOptions {
+    RentalOptions { promo_code: None, redeem_points: 0, return_zone: None, insured: false }
+}
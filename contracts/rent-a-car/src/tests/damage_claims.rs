@@ -0,0 +1,352 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, token, vec, Address, BytesN, Env};
+use crate::{
+    contract::RentACarContractClient,
+    methods::dispute::{CLAIM_WINDOW_SECONDS, RESPONSE_WINDOW_SECONDS},
+    storage::{structs::{beneficiary::Beneficiary, renter_rules::RenterRules}, types::claim_status::ClaimStatus},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+fn evidence(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+/// Lists `owner`'s car with a flat 500 deposit and completes a one-day rental by `renter`
+fn returned_rental(env: &Env, contract: &RentACarContractClient, token_admin: &token::StellarAssetClient, renter: &Address, owner: &Address) {
+    token_admin.mint(renter, &10_000_i128);
+    add_test_car(env, contract, owner, 1000_i128, 0_i128);
+    contract.set_renter_rules(owner, &RenterRules {
+        min_rating_x100: 0,
+        min_completed_rentals: 0,
+        base_deposit: 500,
+        new_multiplier_bps: 10000,
+        bronze_multiplier_bps: 10000,
+        silver_multiplier_bps: 10000,
+        gold_multiplier_bps: 10000,
+    });
+
+    contract.rental(renter, owner, &24, &1000_i128, &no_options());
+    contract.end_rental(renter, owner);
+}
+
+#[test]
+pub fn test_renter_accepts_claim() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+    let car_before = contract.get_car(&owner).available_to_withdraw;
+
+    contract.open_damage_claim(&owner, &renter, &0, &300_i128, &evidence(&env, 1));
+    let claim = contract.get_damage_claim(&renter, &0);
+    assert_eq!(claim.status, ClaimStatus::Open);
+    assert_eq!(claim.amount, 300);
+
+    contract.accept_damage_claim(&renter, &0);
+
+    let claim = contract.get_damage_claim(&renter, &0);
+    assert_eq!(claim.status, ClaimStatus::Accepted);
+    assert_eq!(claim.awarded, 300);
+    assert_eq!(contract.get_car(&owner).available_to_withdraw, car_before + 300);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000 - 300);
+    assert!(contract.get_rental_history(&renter, &0, &1).get(0).unwrap().deposit_settled);
+}
+
+#[test]
+pub fn test_arbiter_resolves_contested_claim() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    contract.set_arbiter(&arbiter);
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+    let car_before = contract.get_car(&owner).available_to_withdraw;
+
+    contract.open_damage_claim(&owner, &renter, &0, &400_i128, &evidence(&env, 1));
+    contract.contest_damage_claim(&renter, &0, &evidence(&env, 2));
+
+    let claim = contract.get_damage_claim(&renter, &0);
+    assert_eq!(claim.status, ClaimStatus::Contested);
+    assert_eq!(claim.renter_evidence_hash, evidence(&env, 2));
+
+    contract.resolve_damage_claim(&arbiter, &renter, &0, &150_i128);
+
+    let claim = contract.get_damage_claim(&renter, &0);
+    assert_eq!(claim.status, ClaimStatus::Resolved);
+    assert_eq!(claim.awarded, 150);
+    assert_eq!(contract.get_car(&owner).available_to_withdraw, car_before + 150);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000 - 150);
+}
+
+#[test]
+pub fn test_arbiter_can_reject_claim() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    contract.set_arbiter(&arbiter);
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+    let car_before = contract.get_car(&owner).available_to_withdraw;
+
+    contract.open_damage_claim(&owner, &renter, &0, &500_i128, &evidence(&env, 1));
+    contract.contest_damage_claim(&renter, &0, &evidence(&env, 2));
+    contract.resolve_damage_claim(&arbiter, &renter, &0, &0_i128);
+
+    assert_eq!(contract.get_car(&owner).available_to_withdraw, car_before);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000);
+}
+
+#[test]
+pub fn test_arbiter_resolves_unanswered_claim() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    contract.set_arbiter(&arbiter);
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &200_i128, &evidence(&env, 1));
+    advance(&env, RESPONSE_WINDOW_SECONDS + 1);
+    contract.resolve_damage_claim(&arbiter, &renter, &0, &200_i128);
+
+    assert_eq!(contract.get_damage_claim(&renter, &0).status, ClaimStatus::Resolved);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000 - 200);
+}
+
+#[test]
+#[should_panic(expected = "ResponseWindowOpen")]
+pub fn test_arbiter_waits_for_renter_response() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    contract.set_arbiter(&arbiter);
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &200_i128, &evidence(&env, 1));
+    contract.resolve_damage_claim(&arbiter, &renter, &0, &200_i128);
+}
+
+#[test]
+#[should_panic(expected = "NotArbiter")]
+pub fn test_only_arbiter_resolves_claims() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    contract.set_arbiter(&arbiter);
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &200_i128, &evidence(&env, 1));
+    contract.contest_damage_claim(&renter, &0, &evidence(&env, 2));
+    contract.resolve_damage_claim(&owner, &renter, &0, &200_i128);
+}
+
+#[test]
+#[should_panic(expected = "ClaimExceedsDeposit")]
+pub fn test_claim_cannot_exceed_deposit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &501_i128, &evidence(&env, 1));
+}
+
+#[test]
+#[should_panic(expected = "ClaimWindowClosed")]
+pub fn test_claim_must_be_opened_within_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    advance(&env, CLAIM_WINDOW_SECONDS + 1);
+    contract.open_damage_claim(&owner, &renter, &0, &100_i128, &evidence(&env, 1));
+}
+
+#[test]
+#[should_panic(expected = "ClaimExists")]
+pub fn test_one_claim_per_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &100_i128, &evidence(&env, 1));
+    contract.open_damage_claim(&owner, &renter, &0, &100_i128, &evidence(&env, 1));
+}
+
+#[test]
+#[should_panic(expected = "NotOwner")]
+pub fn test_only_rental_owner_opens_claim() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    contract.open_damage_claim(&stranger, &renter, &0, &100_i128, &evidence(&env, 1));
+}
+
+#[test]
+#[should_panic(expected = "ClaimNotPending")]
+pub fn test_settled_claim_cannot_be_contested() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &100_i128, &evidence(&env, 1));
+    contract.accept_damage_claim(&renter, &0);
+    contract.contest_damage_claim(&renter, &0, &evidence(&env, 2));
+}
+
+#[test]
+pub fn test_deposit_released_after_claim_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000 - 500);
+
+    advance(&env, CLAIM_WINDOW_SECONDS + 1);
+    contract.release_deposit(&renter, &0);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000);
+}
+
+#[test]
+#[should_panic(expected = "ClaimWindowOpen")]
+pub fn test_deposit_held_during_claim_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    contract.release_deposit(&renter, &0);
+}
+
+#[test]
+#[should_panic(expected = "DepositAlreadySettled")]
+pub fn test_deposit_released_once() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+
+    advance(&env, CLAIM_WINDOW_SECONDS + 1);
+    contract.release_deposit(&renter, &0);
+    contract.release_deposit(&renter, &0);
+}
+
+#[test]
+pub fn test_award_goes_to_holder_during_the_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+    assert_eq!(contract.get_rental_history(&renter, &0, &1).get(0).unwrap().holder, owner);
+
+    // The rental's earnings are paid out to the seller on transfer
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+    let paid_on_transfer = token_client.balance(&owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &300_i128, &evidence(&env, 1));
+    assert_eq!(env.auths()[0].0, owner);
+    contract.accept_damage_claim(&renter, &0);
+
+    // The damage happened under the seller, the buyer's car balance is not credited
+    assert_eq!(token_client.balance(&owner), paid_on_transfer + 300);
+    assert_eq!(contract.get_car(&owner).available_to_withdraw, 0);
+}
+
+#[test]
+pub fn test_award_split_among_co_owners() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    returned_rental(&env, &contract, &token_admin, &renter, &owner);
+    contract.set_car_beneficiaries(&owner, &vec![
+        &env,
+        Beneficiary { address: owner.clone(), share_bps: 6000 },
+        Beneficiary { address: investor.clone(), share_bps: 4000 },
+    ]);
+    let car_before = contract.get_car(&owner).available_to_withdraw;
+
+    contract.open_damage_claim(&owner, &renter, &0, &300_i128, &evidence(&env, 1));
+    contract.accept_damage_claim(&renter, &0);
+
+    // The award is car income like any other, shared by the co-owners
+    assert_eq!(contract.get_car(&owner).available_to_withdraw, car_before);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &owner), 180);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &investor), 120);
+}
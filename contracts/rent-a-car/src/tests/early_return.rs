@@ -0,0 +1,119 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Vec};
+use crate::{
+    storage::{contract_balance::read_contract_balance, structs::beneficiary::Beneficiary, types::early_return::EarlyReturnPolicy},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+const DAY: u64 = 86400;
+const HOUR: u64 = 3600;
+
+#[test]
+pub fn test_early_return_defaults_to_no_refund() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    assert_eq!(contract.get_early_return_policy(&owner), EarlyReturnPolicy::NoRefund);
+
+    let start = env.ledger().timestamp();
+    contract.rental(&renter, &owner, &168, &7000_i128, &no_options());
+
+    env.ledger().set_timestamp(start + 2 * DAY);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(contract.get_available_to_withdraw(&owner), 7000);
+    assert_eq!(token_client.balance(&renter), 3000);
+}
+
+#[test]
+pub fn test_early_return_pro_rated_refunds_unused_full_days() {
+    let ContractTest { env, contract, token, address, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128); // 10% commission
+    contract.set_early_return_policy(&owner, &EarlyReturnPolicy::ProRated);
+
+    let start = env.ledger().timestamp();
+    contract.rental(&renter, &owner, &168, &7000_i128, &no_options());
+    assert_eq!(token_client.balance(&renter), 2300);
+
+    // 4 days and 23 hours remain, only the 4 full days are refunded
+    env.ledger().set_timestamp(start + 2 * DAY + HOUR);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(token_client.balance(&renter), 2300 + 4000 + 400);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 3000);
+    assert_eq!(contract.get_admin_commission_balance(), 300);
+
+    let contract_balance = env.as_contract(&address, || read_contract_balance(&env));
+    assert_eq!(contract_balance, 3300);
+}
+
+#[test]
+pub fn test_early_return_pro_rated_with_fee() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128); // 10% commission
+    contract.set_early_return_policy(&owner, &EarlyReturnPolicy::ProRatedWithFee(1000)); // 10% fee
+
+    let start = env.ledger().timestamp();
+    contract.rental(&renter, &owner, &168, &7000_i128, &no_options());
+
+    env.ledger().set_timestamp(start + 3 * DAY);
+    contract.end_rental(&renter, &owner);
+
+    // 4 unused days = 4000, minus the 10% fee = 3600 plus its 360 commission
+    assert_eq!(token_client.balance(&renter), 2300 + 3600 + 360);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 3400);
+    assert_eq!(contract.get_admin_commission_balance(), 340);
+}
+
+#[test]
+#[should_panic(expected = "InvalidFee")]
+pub fn test_early_return_fee_cannot_exceed_100_percent() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.set_early_return_policy(&owner, &EarlyReturnPolicy::ProRatedWithFee(10001));
+}
+
+#[test]
+pub fn test_early_return_policy_survives_clearing_beneficiaries() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_early_return_policy(&owner, &EarlyReturnPolicy::ProRated);
+
+    contract.set_car_beneficiaries(&owner, &vec![
+        &env,
+        Beneficiary { address: owner.clone(), share_bps: 5000 },
+        Beneficiary { address: partner, share_bps: 5000 },
+    ]);
+    contract.set_car_beneficiaries(&owner, &Vec::new(&env));
+
+    assert_eq!(contract.get_early_return_policy(&owner), EarlyReturnPolicy::ProRated);
+}
@@ -0,0 +1,76 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::{
+    storage::{contract_balance::read_contract_balance, rental::read_rental},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+const DAY: u64 = 86400;
+
+#[test]
+pub fn test_extend_rental_successfully() {
+    let ContractTest { env, contract, token, address, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 500_i128); // 5% commission
+
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+    let before = env.as_contract(&address, || read_rental(&env, &renter, &owner).unwrap());
+
+    env.ledger().set_timestamp(before.start_time + DAY);
+    contract.extend_rental(&renter, &owner, &48, &2000_i128);
+
+    let rental = env.as_contract(&address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.duration_hours, 120);
+    assert_eq!(rental.amount, 5000);
+    assert_eq!(rental.commission, 250);
+    assert_eq!(rental.end_time, before.end_time + 2 * DAY);
+
+    assert_eq!(contract.get_available_to_withdraw(&owner), 5000);
+    assert_eq!(contract.get_admin_commission_balance(), 250);
+    assert_eq!(token_client.balance(&renter), 10_000 - 5250);
+
+    let contract_balance = env.as_contract(&address, || read_contract_balance(&env));
+    assert_eq!(contract_balance, 5250);
+}
+
+#[test]
+#[should_panic(expected = "AmountMismatch")]
+pub fn test_extend_rental_requires_exact_payment() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+    contract.extend_rental(&renter, &owner, &48, &1500_i128);
+}
+
+#[test]
+#[should_panic(expected = "Rental period has already ended")]
+pub fn test_extend_rental_fails_after_end_time() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    let start = env.ledger().timestamp();
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+
+    env.ledger().set_timestamp(start + 3 * DAY);
+    contract.extend_rental(&renter, &owner, &24, &1000_i128);
+}
@@ -6,9 +6,9 @@ pub fn test_get_car_status_returns_available() {
     let ContractTest { env, contract, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
-    let brand = String::from_slice(&env, "TestBrand");
-    let model = String::from_slice(&env, "TestModel");
-    let color = String::from_slice(&env, "Black");
+    let brand = String::from_str(&env, "TestBrand");
+    let model = String::from_str(&env, "TestModel");
+    let color = String::from_str(&env, "Black");
     let passengers = 4_u32;
     let ac = true;
     let price_per_day = 1500_i128;
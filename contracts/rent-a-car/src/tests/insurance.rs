@@ -0,0 +1,262 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, token, Address, BytesN, Env};
+use insurance_pool::{InsurancePoolContract, InsurancePoolContractClient};
+use crate::{
+    contract::RentACarContractClient,
+    methods::dispute::CLAIM_WINDOW_SECONDS,
+    storage::structs::{insurance::InsuranceConfig, rental_options::RentalOptions, renter_rules::RenterRules},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+fn insured() -> RentalOptions {
+    RentalOptions { insured: true, ..no_options() }
+}
+
+fn flat_deposit(base_deposit: i128) -> RenterRules {
+    RenterRules {
+        min_rating_x100: 0,
+        min_completed_rentals: 0,
+        base_deposit,
+        new_multiplier_bps: 10000,
+        bronze_multiplier_bps: 10000,
+        silver_multiplier_bps: 10000,
+        gold_multiplier_bps: 10000,
+    }
+}
+
+/// Deploys a pool linked to `contract` with `liquidity` from a provider; premiums are 5% for up to 2000 of coverage
+fn setup_pool<'a>(
+    env: &Env,
+    contract: &RentACarContractClient,
+    token_client: &token::Client,
+    token_admin: &token::StellarAssetClient,
+    admin: &Address,
+    liquidity: i128,
+) -> InsurancePoolContractClient<'a> {
+    let pool_id = env.register(InsurancePoolContract, (admin, &token_client.address));
+    let pool = InsurancePoolContractClient::new(env, &pool_id);
+    pool.set_rent_a_car(&contract.address);
+
+    let provider = Address::generate(env);
+    token_admin.mint(&provider, &liquidity);
+    pool.deposit(&provider, &liquidity);
+
+    contract.set_insurance_config(&InsuranceConfig { pool: pool_id, premium_bps: 500, max_coverage: 2000 });
+    pool
+}
+
+fn evidence(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[1; 32])
+}
+
+#[test]
+pub fn test_insured_rental_pays_premium_to_pool() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    let pool = setup_pool(&env, &contract, &token_client, &token_admin, &admin, 5000);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &insured());
+
+    let rental = contract.get_rental(&renter, &owner);
+    assert_eq!(rental.premium, 50);
+    assert_eq!(rental.coverage, 2000);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000 - 50);
+    assert_eq!(token_client.balance(&pool.address), 5050);
+    assert_eq!(pool.get_pool().premiums_collected, 50);
+
+    contract.end_rental(&renter, &owner);
+    let record = contract.get_rental_history(&renter, &0, &1).get(0).unwrap();
+    assert_eq!(record.premium, 50);
+    assert!(!record.deposit_settled);
+}
+
+#[test]
+pub fn test_pool_covers_claim_before_deposit() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    let pool = setup_pool(&env, &contract, &token_client, &token_admin, &admin, 5000);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_renter_rules(&owner, &flat_deposit(500));
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &insured());
+    contract.end_rental(&renter, &owner);
+    let car_before = contract.get_car(&owner).available_to_withdraw;
+
+    // Beyond the deposit but within deposit plus coverage
+    contract.open_damage_claim(&owner, &renter, &0, &2300_i128, &evidence(&env));
+    contract.accept_damage_claim(&renter, &0);
+
+    let claim = contract.get_damage_claim(&renter, &0);
+    assert_eq!(claim.awarded, 2300);
+    assert_eq!(claim.covered, 2000);
+    assert_eq!(pool.get_pool().claims_paid, 2000);
+    assert_eq!(contract.get_car(&owner).available_to_withdraw, car_before + 2300);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000 - 50 - 300);
+
+    // The owner can withdraw the full award
+    contract.payout_all(&owner);
+    assert_eq!(token_client.balance(&owner), car_before + 2300);
+}
+
+#[test]
+pub fn test_short_pool_falls_back_to_deposit() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    let pool = setup_pool(&env, &contract, &token_client, &token_admin, &admin, 100);
+    contract.set_arbiter(&arbiter);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_renter_rules(&owner, &flat_deposit(500));
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &insured());
+    contract.end_rental(&renter, &owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &400_i128, &evidence(&env));
+    contract.contest_damage_claim(&renter, &0, &evidence(&env));
+    contract.resolve_damage_claim(&arbiter, &renter, &0, &400_i128);
+
+    // The pool only held the provider's 100 plus the 50 premium
+    assert_eq!(contract.get_damage_claim(&renter, &0).covered, 150);
+    assert_eq!(pool.get_pool().total_assets, 0);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000 - 50 - 250);
+}
+
+#[test]
+pub fn test_insured_rental_without_deposit_can_be_claimed() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    setup_pool(&env, &contract, &token_client, &token_admin, &admin, 5000);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &insured());
+    contract.end_rental(&renter, &owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &800_i128, &evidence(&env));
+    contract.accept_damage_claim(&renter, &0);
+
+    assert_eq!(contract.get_damage_claim(&renter, &0).covered, 800);
+    assert_eq!(token_client.balance(&renter), 10_000 - 1000 - 50);
+}
+
+#[test]
+pub fn test_claim_paid_by_pool_that_sold_coverage() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    let old_pool = setup_pool(&env, &contract, &token_client, &token_admin, &admin, 5000);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &insured());
+    assert_eq!(contract.get_rental(&renter, &owner).insurance_pool, Some(old_pool.address.clone()));
+    contract.end_rental(&renter, &owner);
+
+    // Switching pools does not move the coverage already sold
+    let new_pool = setup_pool(&env, &contract, &token_client, &token_admin, &admin, 5000);
+
+    contract.open_damage_claim(&owner, &renter, &0, &800_i128, &evidence(&env));
+    contract.accept_damage_claim(&renter, &0);
+
+    assert_eq!(old_pool.get_pool().claims_paid, 800);
+    assert_eq!(new_pool.get_pool().claims_paid, 0);
+}
+
+#[test]
+pub fn test_coverage_reserved_until_deposit_released() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    let pool = setup_pool(&env, &contract, &token_client, &token_admin, &admin, 5000);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &insured());
+    assert_eq!(pool.get_pool().reserved, 2000);
+
+    // Still claimable through the claim window after the return
+    contract.end_rental(&renter, &owner);
+    assert_eq!(pool.get_pool().reserved, 2000);
+
+    env.ledger().with_mut(|li| li.timestamp += CLAIM_WINDOW_SECONDS + 1);
+    contract.release_deposit(&renter, &0);
+    assert_eq!(pool.get_pool().reserved, 0);
+}
+
+#[test]
+#[should_panic(expected = "ClaimExceedsDeposit")]
+pub fn test_claim_capped_at_deposit_plus_coverage() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    setup_pool(&env, &contract, &token_client, &token_admin, &admin, 5000);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_renter_rules(&owner, &flat_deposit(500));
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &insured());
+    contract.end_rental(&renter, &owner);
+
+    contract.open_damage_claim(&owner, &renter, &0, &2501_i128, &evidence(&env));
+}
+
+#[test]
+#[should_panic(expected = "InsuranceNotConfigured")]
+pub fn test_coverage_requires_configured_pool() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &insured());
+}
+
+#[test]
+#[should_panic(expected = "InvalidInsuranceConfig")]
+pub fn test_premium_cannot_exceed_base_amount() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+    let pool = Address::generate(&env);
+    contract.set_insurance_config(&InsuranceConfig { pool, premium_bps: 10001, max_coverage: 0 });
+}
@@ -0,0 +1,163 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address};
+use crate::{
+    storage::types::licence_class::LicenceClass,
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+#[test]
+pub fn test_verified_driver_can_rent() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_kyc_required(&true);
+    contract.set_verifier(&verifier, &true);
+    contract.set_required_licence(&owner, &Some(LicenceClass::B));
+
+    contract.attest_licence(&verifier, &renter, &vec![&env, LicenceClass::A, LicenceClass::B], &(30 * 86_400));
+    assert_eq!(contract.get_attestation(&renter).verifier, verifier);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    assert!(contract.has_rental(&renter, &owner));
+}
+
+#[test]
+#[should_panic(expected = "DriverNotVerified")]
+pub fn test_unverified_renter_is_rejected() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_kyc_required(&true);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "AttestationExpired")]
+pub fn test_licence_must_outlast_the_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_kyc_required(&true);
+    contract.set_verifier(&verifier, &true);
+    contract.attest_licence(&verifier, &renter, &vec![&env, LicenceClass::B], &(2 * 86_400));
+
+    env.ledger().set_timestamp(86_400);
+    contract.rental(&renter, &owner, &48, &2000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "LicenceClassMismatch")]
+pub fn test_licence_class_must_match_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_kyc_required(&true);
+    contract.set_verifier(&verifier, &true);
+    contract.set_required_licence(&owner, &Some(LicenceClass::D));
+    contract.attest_licence(&verifier, &renter, &vec![&env, LicenceClass::B], &(30 * 86_400));
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "DriverNotVerified")]
+pub fn test_removing_verifier_voids_its_attestations() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_kyc_required(&true);
+    contract.set_verifier(&verifier, &true);
+    contract.attest_licence(&verifier, &renter, &vec![&env, LicenceClass::B], &(30 * 86_400));
+    contract.set_verifier(&verifier, &false);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "NotVerifier")]
+pub fn test_only_approved_verifiers_attest() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let impostor = Address::generate(&env);
+    let driver = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.attest_licence(&impostor, &driver, &vec![&env, LicenceClass::B], &(30 * 86_400));
+}
+
+#[test]
+#[should_panic(expected = "AttestationExpired")]
+pub fn test_extension_cannot_outlast_the_licence() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_kyc_required(&true);
+    contract.set_verifier(&verifier, &true);
+    contract.attest_licence(&verifier, &renter, &vec![&env, LicenceClass::B], &(2 * 86_400));
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.extend_rental(&renter, &owner, &48, &2000_i128);
+}
+
+#[test]
+#[should_panic(expected = "DriverNotVerified")]
+pub fn test_extension_requires_approved_verifier() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_kyc_required(&true);
+    contract.set_verifier(&verifier, &true);
+    contract.attest_licence(&verifier, &renter, &vec![&env, LicenceClass::B], &(30 * 86_400));
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.set_verifier(&verifier, &false);
+    contract.extend_rental(&renter, &owner, &24, &1000_i128);
+}
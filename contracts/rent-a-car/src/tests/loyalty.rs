@@ -0,0 +1,82 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{
+    storage::structs::rental_options::RentalOptions,
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+#[test]
+pub fn test_completed_rental_earns_and_redeems_points() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &20_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128); // 10% commission
+    contract.set_loyalty_config(&1000, &2); // 10% of amount paid, 2 tokens per point
+
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+    assert_eq!(contract.get_loyalty_account(&renter).points, 0);
+    contract.end_rental(&renter, &owner);
+
+    let account = contract.get_loyalty_account(&renter);
+    assert_eq!(account.points, 300);
+    assert_eq!(account.total_earned, 300);
+    assert_eq!(account.rentals_rewarded, 1);
+
+    // 250 points are worth 500 off the next rental
+    let options = RentalOptions { redeem_points: 250, ..no_options() };
+    contract.rental(&renter, &owner, &72, &2500_i128, &options);
+
+    let rental = contract.get_rental(&renter, &owner);
+    assert_eq!(rental.discount, 500);
+    assert_eq!(rental.points_redeemed, 250);
+    assert_eq!(rental.commission, 250);
+    assert_eq!(token_client.balance(&renter), 20_000 - 3300 - 2750);
+
+    let account = contract.get_loyalty_account(&renter);
+    assert_eq!(account.points, 50);
+    assert_eq!(account.total_redeemed, 250);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient loyalty points")]
+pub fn test_cannot_redeem_more_points_than_balance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128);
+    contract.set_loyalty_config(&1000, &1);
+
+    let options = RentalOptions { redeem_points: 10, ..no_options() };
+    contract.rental(&renter, &owner, &24, &990_i128, &options);
+}
+
+#[test]
+#[should_panic(expected = "Loyalty redemption is disabled")]
+pub fn test_redemption_requires_point_value() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &20_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128);
+    contract.set_loyalty_config(&1000, &0);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+    assert_eq!(contract.get_loyalty_account(&renter).points, 100);
+
+    let options = RentalOptions { redeem_points: 10, ..no_options() };
+    contract.rental(&renter, &owner, &24, &990_i128, &options);
+}
@@ -9,4 +9,29 @@ pub mod remove_car;
 pub mod payout_owner;
 pub mod payout_owner_restricted;
 pub mod commission;
-pub mod auth;
\ No newline at end of file
+pub mod auth;
+pub mod payout_destination;
+pub mod beneficiaries;
+pub mod streaming;
+pub mod early_return;
+pub mod extend_rental;
+pub mod pricing;
+pub mod price_rules;
+pub mod promo_code;
+pub mod referral;
+pub mod loyalty;
+pub mod rental_history;
+pub mod car_discovery;
+pub mod car_owners;
+pub mod car_details;
+pub mod zones;
+pub mod kyc;
+pub mod blocklist;
+pub mod ratings;
+pub mod renter_rules;
+pub mod damage_claims;
+pub mod insurance;
+pub mod car_tokens;
+pub mod rental_passes;
+pub mod car_access;
+pub mod additional_drivers;
@@ -0,0 +1,130 @@
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use crate::{
+    storage::{car::read_car, contract_balance::read_contract_balance},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+#[test]
+pub fn test_payout_owner_goes_to_registered_destination() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1500_i128, 0_i128);
+
+    assert_eq!(contract.get_payout_destination(&owner), owner);
+    contract.set_payout_destination(&owner, &treasury);
+    assert_eq!(contract.get_payout_destination(&owner), treasury);
+
+    contract.rental(&renter, &owner, &72, &4500_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+    contract.payout_owner(&owner, &1000_i128);
+
+    assert_eq!(token_client.balance(&treasury), 1000);
+    assert_eq!(token_client.balance(&owner), 0);
+
+    // Resetting to the owner clears the destination
+    contract.set_payout_destination(&owner, &owner);
+    contract.payout_owner(&owner, &500_i128);
+    assert_eq!(token_client.balance(&owner), 500);
+}
+
+#[test]
+pub fn test_payout_all_drains_available_balance() {
+    let ContractTest { env, contract, token, address, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1500_i128, 500_i128);
+
+    contract.rental(&renter, &owner, &72, &4500_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+
+    let paid = contract.payout_all(&owner);
+    assert_eq!(paid, 4500);
+    assert_eq!(token_client.balance(&owner), 4500);
+
+    let car = env.as_contract(&address, || read_car(&env, &owner).unwrap());
+    assert_eq!(car.available_to_withdraw, 0);
+
+    // Only the admin commission remains in the contract
+    let contract_balance = env.as_contract(&address, || read_contract_balance(&env));
+    assert_eq!(contract_balance, contract.get_admin_commission_balance());
+}
+
+#[test]
+#[should_panic(expected = "No funds available to withdraw")]
+pub fn test_payout_all_fails_without_funds() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1500_i128, 0_i128);
+
+    contract.payout_all(&owner);
+}
+
+#[test]
+pub fn test_batch_payout_skips_rented_and_empty_cars() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner_a = Address::generate(&env);
+    let owner_b = Address::generate(&env);
+    let owner_c = Address::generate(&env);
+    let treasury_b = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &20_000_i128);
+    add_test_car(&env, &contract, &owner_a, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &owner_b, 2000_i128, 0_i128);
+    add_test_car(&env, &contract, &owner_c, 3000_i128, 0_i128);
+    contract.set_payout_destination(&owner_b, &treasury_b);
+
+    contract.rental(&renter, &owner_a, &48, &2000_i128, &no_options());
+    contract.end_rental(&renter, &owner_a);
+    contract.rental(&renter, &owner_b, &24, &2000_i128, &no_options());
+    contract.end_rental(&renter, &owner_b);
+    contract.rental(&renter, &owner_c, &24, &3000_i128, &no_options());
+
+    let total = contract.batch_payout(&vec![&env, owner_a.clone(), owner_b.clone(), owner_c.clone()]);
+
+    assert_eq!(total, 4000);
+    assert_eq!(token_client.balance(&owner_a), 2000);
+    assert_eq!(token_client.balance(&treasury_b), 2000);
+    assert_eq!(token_client.balance(&owner_c), 0);
+    assert_eq!(contract.get_available_to_withdraw(&owner_c), 3000);
+}
+
+#[test]
+pub fn test_batch_payout_skips_owners_without_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let removed_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+
+    let total = contract.batch_payout(&vec![&env, removed_owner.clone(), owner.clone()]);
+
+    assert_eq!(total, 1000);
+    assert_eq!(token_client.balance(&owner), 1000);
+}
@@ -1,7 +1,7 @@
 use soroban_sdk::{testutils::Address as _, Address};
 use crate::{
     storage::{car::read_car, contract_balance::read_contract_balance},
-    tests::config::contract::ContractTest,
+    tests::config::{contract::ContractTest, utils::no_options},
 };
 
 #[test]
@@ -11,7 +11,7 @@ pub fn test_payout_owner_successfully() {
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
-    let total_days = 3;
+    let duration_hours = 72; // 3 days
     let amount = 4500_i128;
     
     let (_, token_admin, _) = token;
@@ -20,9 +20,9 @@ pub fn test_payout_owner_successfully() {
     env.mock_all_auths();
     token_admin.mint(&renter, &amount_mint);
 
-    let brand = soroban_sdk::String::from_slice(&env, "TestBrand");
-    let model = soroban_sdk::String::from_slice(&env, "TestModel");
-    let color = soroban_sdk::String::from_slice(&env, "Black");
+    let brand = soroban_sdk::String::from_str(&env, "TestBrand");
+    let model = soroban_sdk::String::from_str(&env, "TestModel");
+    let color = soroban_sdk::String::from_str(&env, "Black");
     let passengers = 4_u32;
     let ac = true;
 
@@ -30,7 +30,7 @@ pub fn test_payout_owner_successfully() {
     contract.add_car(&owner, &brand, &model, &color, &passengers, &ac, &price_per_day, &0_i128); // 0% commission
     
     env.mock_all_auths();
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &owner, &duration_hours, &amount, &no_options());
 
     // Note: With commission feature, the contract balance includes commission.
     // Since commission defaults to 0, the balance should equal the amount.
@@ -1,7 +1,7 @@
 use soroban_sdk::{testutils::Address as _, Address};
 use crate::{
     storage::{car::read_car, types::car_status::CarStatus},
-    tests::config::contract::ContractTest,
+    tests::config::{contract::ContractTest, utils::no_options},
 };
 
 #[test]
@@ -12,7 +12,7 @@ pub fn test_payout_owner_fails_when_car_is_rented() {
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
-    let total_days = 3;
+    let duration_hours = 72; // 3 days
     let amount = 4500_i128;
     
     let (_, token_admin, _) = token;
@@ -21,9 +21,9 @@ pub fn test_payout_owner_fails_when_car_is_rented() {
     env.mock_all_auths();
     token_admin.mint(&renter, &amount_mint);
 
-    let brand = soroban_sdk::String::from_slice(&env, "TestBrand");
-    let model = soroban_sdk::String::from_slice(&env, "TestModel");
-    let color = soroban_sdk::String::from_slice(&env, "Black");
+    let brand = soroban_sdk::String::from_str(&env, "TestBrand");
+    let model = soroban_sdk::String::from_str(&env, "TestModel");
+    let color = soroban_sdk::String::from_str(&env, "Black");
     let passengers = 4_u32;
     let ac = true;
 
@@ -31,7 +31,7 @@ pub fn test_payout_owner_fails_when_car_is_rented() {
     contract.add_car(&owner, &brand, &model, &color, &passengers, &ac, &price_per_day, &500_i128); // 5% commission
     
     env.mock_all_auths();
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &owner, &duration_hours, &amount, &no_options());
 
     // Verificar que el auto está alquilado
     let car = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
@@ -49,7 +49,7 @@ pub fn test_payout_owner_succeeds_after_end_rental() {
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
-    let total_days = 3;
+    let duration_hours = 72; // 3 days
     let amount = 4500_i128;
     
     let (_, token_admin, _) = token;
@@ -58,9 +58,9 @@ pub fn test_payout_owner_succeeds_after_end_rental() {
     env.mock_all_auths();
     token_admin.mint(&renter, &amount_mint);
 
-    let brand = soroban_sdk::String::from_slice(&env, "TestBrand");
-    let model = soroban_sdk::String::from_slice(&env, "TestModel");
-    let color = soroban_sdk::String::from_slice(&env, "Black");
+    let brand = soroban_sdk::String::from_str(&env, "TestBrand");
+    let model = soroban_sdk::String::from_str(&env, "TestModel");
+    let color = soroban_sdk::String::from_str(&env, "Black");
     let passengers = 4_u32;
     let ac = true;
 
@@ -68,7 +68,7 @@ pub fn test_payout_owner_succeeds_after_end_rental() {
     contract.add_car(&owner, &brand, &model, &color, &passengers, &ac, &price_per_day, &500_i128); // 5% commission
     
     env.mock_all_auths();
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &owner, &duration_hours, &amount, &no_options());
 
     // Verificar que el auto está alquilado
     let car_before = env.as_contract(&contract.address, || read_car(&env, &owner).unwrap());
@@ -0,0 +1,168 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Env, Vec};
+use crate::{
+    storage::structs::{beneficiary::Beneficiary, price_rules::{PriceRules, SeasonalRate}},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+const HOUR: u64 = 3600;
+const DAY: u64 = 86400;
+const FRIDAY: u64 = 1704412800; // 2024-01-05 00:00 UTC
+const MONDAY: u64 = 1719792000; // 2024-07-01 00:00 UTC
+
+fn weekend_rules(env: &Env, surcharge_bps: u32) -> PriceRules {
+    PriceRules {
+        weekend_surcharge_bps: surcharge_bps,
+        seasons: Vec::new(env),
+        weekly_discount_bps: 0,
+        monthly_discount_bps: 0,
+    }
+}
+
+#[test]
+pub fn test_weekend_surcharge_applies_per_day() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_price_rules(&owner, &weekend_rules(&env, 2000)); // +20%
+
+    // Friday, Saturday, Sunday
+    assert_eq!(contract.quote_rental(&owner, &FRIDAY, &72), 3400);
+
+    env.ledger().set_timestamp(FRIDAY);
+    contract.rental(&renter, &owner, &72, &3400_i128, &no_options());
+    assert_eq!(contract.get_available_to_withdraw(&owner), 3400);
+}
+
+#[test]
+pub fn test_rental_days_follow_ledger_timestamp_boundaries() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_car_pricing(&owner, &100_i128, &0, &0);
+    contract.set_price_rules(&owner, &weekend_rules(&env, 2000));
+
+    // A day starting Friday 23:00 is a Friday day, the next one starts on Saturday
+    assert_eq!(contract.quote_rental(&owner, &(FRIDAY + 23 * HOUR), &24), 1000);
+    assert_eq!(contract.quote_rental(&owner, &(FRIDAY + 23 * HOUR), &48), 2200);
+    assert_eq!(contract.quote_rental(&owner, &(FRIDAY + DAY), &24), 1200);
+
+    // Leftover hours take the adjustment of the day they fall in
+    assert_eq!(contract.quote_rental(&owner, &FRIDAY, &27), 1000 + 360);
+
+    // Monday 00:00 is no longer weekend
+    assert_eq!(contract.quote_rental(&owner, &(FRIDAY + 3 * DAY), &24), 1000);
+}
+
+#[test]
+pub fn test_seasonal_rate_applies_to_days_inside_range() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    let rules = PriceRules {
+        weekend_surcharge_bps: 0,
+        seasons: vec![&env, SeasonalRate { start: MONDAY, end: MONDAY + DAY, adjustment_bps: 5000 }],
+        weekly_discount_bps: 0,
+        monthly_discount_bps: 0,
+    };
+    contract.set_price_rules(&owner, &rules);
+
+    // Only the Monday falls inside the season, the season end is exclusive
+    assert_eq!(contract.quote_rental(&owner, &MONDAY, &48), 1500 + 1000);
+    assert_eq!(contract.quote_rental(&owner, &(MONDAY - DAY), &24), 1000);
+}
+
+#[test]
+pub fn test_long_stay_discounts_apply_to_total() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    let rules = PriceRules {
+        weekend_surcharge_bps: 2000,
+        seasons: Vec::new(&env),
+        weekly_discount_bps: 1000,
+        monthly_discount_bps: 2500,
+    };
+    contract.set_price_rules(&owner, &rules);
+
+    // Monday to Sunday: 5 weekdays + 2 weekend days, minus 10%
+    assert_eq!(contract.quote_rental(&owner, &MONDAY, &(7 * 24)), 7400 * 9 / 10);
+    // 6 days don't qualify
+    assert_eq!(contract.quote_rental(&owner, &MONDAY, &(6 * 24)), 6200);
+    // 30 days from Monday 2024-07-01 include 8 weekend days, minus 25%
+    assert_eq!(contract.quote_rental(&owner, &MONDAY, &(30 * 24)), 31600 * 3 / 4);
+}
+
+#[test]
+#[should_panic(expected = "AmountMismatch")]
+pub fn test_rental_rejects_base_price_when_rules_apply() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_price_rules(&owner, &weekend_rules(&env, 2000));
+
+    env.ledger().set_timestamp(FRIDAY + DAY);
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "InvalidPriceRule")]
+pub fn test_price_rules_reject_empty_season() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    let rules = PriceRules {
+        weekend_surcharge_bps: 0,
+        seasons: vec![&env, SeasonalRate { start: MONDAY, end: MONDAY, adjustment_bps: 1000 }],
+        weekly_discount_bps: 0,
+        monthly_discount_bps: 0,
+    };
+    contract.set_price_rules(&owner, &rules);
+}
+
+#[test]
+pub fn test_price_rules_survive_clearing_beneficiaries() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_price_rules(&owner, &weekend_rules(&env, 2000));
+
+    contract.set_car_beneficiaries(&owner, &vec![
+        &env,
+        Beneficiary { address: owner.clone(), share_bps: 5000 },
+        Beneficiary { address: partner, share_bps: 5000 },
+    ]);
+    contract.set_car_beneficiaries(&owner, &Vec::new(&env));
+
+    assert_eq!(contract.get_price_rules(&owner), weekend_rules(&env, 2000));
+}
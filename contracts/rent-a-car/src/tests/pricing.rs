@@ -0,0 +1,96 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{
+    storage::rental::read_rental,
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+#[test]
+pub fn test_rental_with_hourly_rate() {
+    let ContractTest { env, contract, token, address, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 2400_i128, 0_i128);
+    contract.set_car_pricing(&owner, &150_i128, &4, &0);
+
+    // One day plus 2 hours
+    contract.rental(&renter, &owner, &26, &2700_i128, &no_options());
+
+    let rental = env.as_contract(&address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.duration_hours, 26);
+    assert_eq!(rental.end_time, rental.start_time + 26 * 3600);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 2700);
+}
+
+#[test]
+pub fn test_leftover_hours_never_cost_more_than_a_day() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 2400_i128, 0_i128);
+    contract.set_car_pricing(&owner, &150_i128, &0, &0);
+
+    // 23 hours at 150 would be 3450, capped at the daily price
+    contract.rental(&renter, &owner, &23, &2400_i128, &no_options());
+    assert_eq!(contract.get_available_to_withdraw(&owner), 2400);
+}
+
+#[test]
+#[should_panic(expected = "InvalidDuration")]
+pub fn test_partial_days_require_hourly_rate() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 2400_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &30, &3000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "InvalidDuration")]
+pub fn test_rental_below_minimum_duration() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 2400_i128, 0_i128);
+    contract.set_car_pricing(&owner, &150_i128, &4, &0);
+
+    contract.rental(&renter, &owner, &3, &450_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "InvalidDuration")]
+pub fn test_extension_cannot_exceed_maximum_duration() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &20_000_i128);
+    add_test_car(&env, &contract, &owner, 2400_i128, 0_i128);
+    contract.set_car_pricing(&owner, &0_i128, &0, &72);
+
+    contract.rental(&renter, &owner, &48, &4800_i128, &no_options());
+    contract.extend_rental(&renter, &owner, &48, &4800_i128);
+}
@@ -0,0 +1,153 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, BytesN, Env, String};
+use crate::{
+    storage::{rental::read_rental, structs::rental_options::RentalOptions, types::discount::Discount},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+fn code_hash(env: &Env, code: &str) -> BytesN<32> {
+    env.crypto().sha256(&Bytes::from_slice(env, code.as_bytes())).to_bytes()
+}
+
+fn with_code(env: &Env, code: &str) -> RentalOptions {
+    RentalOptions { promo_code: Some(String::from_str(env, code)), ..no_options() }
+}
+
+#[test]
+pub fn test_admin_percent_code_discounts_base_and_commission() {
+    let ContractTest { env, contract, token, address, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 500_i128); // 5% commission
+
+    contract.create_promo_code(&admin, &code_hash(&env, "SUMMER10"), &Discount::Percent(1000), &0, &0, &0);
+
+    // 3000 base - 10% = 2700, commission 5% of 2700 = 135
+    contract.rental(&renter, &owner, &72, &2700_i128, &with_code(&env, "SUMMER10"));
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 2835);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 2700);
+    assert_eq!(contract.get_admin_commission_balance(), 135);
+
+    let rental = env.as_contract(&address, || read_rental(&env, &renter, &owner).unwrap());
+    assert_eq!(rental.discount, 300);
+    assert_eq!(contract.get_promo_code(&code_hash(&env, "SUMMER10")).uses, 1);
+}
+
+#[test]
+#[should_panic(expected = "Promo code not valid for this car")]
+pub fn test_owner_code_only_applies_to_own_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &other_owner, 1000_i128, 0_i128);
+
+    contract.create_promo_code(&owner, &code_hash(&env, "MYCAR"), &Discount::Fixed(200), &0, &0, &0);
+    assert_eq!(contract.get_promo_code(&code_hash(&env, "MYCAR")).car_owner, Some(owner.clone()));
+
+    contract.rental(&renter, &other_owner, &24, &800_i128, &with_code(&env, "MYCAR"));
+}
+
+#[test]
+#[should_panic(expected = "Promo code usage limit reached")]
+pub fn test_promo_code_usage_cap() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner_a = Address::generate(&env);
+    let owner_b = Address::generate(&env);
+    let renter_a = Address::generate(&env);
+    let renter_b = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter_a, &10_000_i128);
+    token_admin.mint(&renter_b, &10_000_i128);
+    add_test_car(&env, &contract, &owner_a, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &owner_b, 1000_i128, 0_i128);
+
+    contract.create_promo_code(&admin, &code_hash(&env, "ONCE"), &Discount::Fixed(100), &1, &0, &0);
+
+    contract.rental(&renter_a, &owner_a, &24, &900_i128, &with_code(&env, "ONCE"));
+    contract.rental(&renter_b, &owner_b, &24, &900_i128, &with_code(&env, "ONCE"));
+}
+
+#[test]
+#[should_panic(expected = "Promo code limit reached for renter")]
+pub fn test_promo_code_per_renter_limit() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.create_promo_code(&admin, &code_hash(&env, "WELCOME"), &Discount::Fixed(100), &0, &1, &0);
+
+    contract.rental(&renter, &owner, &24, &900_i128, &with_code(&env, "WELCOME"));
+    contract.end_rental(&renter, &owner);
+    contract.rental(&renter, &owner, &24, &900_i128, &with_code(&env, "WELCOME"));
+}
+
+#[test]
+#[should_panic(expected = "Promo code expired")]
+pub fn test_promo_code_expiry() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.create_promo_code(&admin, &code_hash(&env, "FLASH"), &Discount::Percent(2000), &0, &0, &1000);
+
+    env.ledger().set_timestamp(1001);
+    contract.rental(&renter, &owner, &24, &800_i128, &with_code(&env, "FLASH"));
+}
+
+#[test]
+#[should_panic(expected = "Only the admin or a car owner can create promo codes")]
+pub fn test_promo_code_creator_must_be_admin_or_owner() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.create_promo_code(&stranger, &code_hash(&env, "FAKE"), &Discount::Percent(5000), &0, &0, &0);
+}
+
+#[test]
+pub fn test_owner_codes_follow_the_car_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+
+    // After the sale the car's codes are managed by the buyer, not the original lister
+    contract.create_promo_code(&owner, &code_hash(&env, "NEWOWNER"), &Discount::Fixed(200), &0, &0, &0);
+    assert_eq!(env.auths()[0].0, buyer);
+
+    contract.disable_promo_code(&owner, &code_hash(&env, "NEWOWNER"));
+    assert_eq!(env.auths()[0].0, buyer);
+    assert!(!contract.get_promo_code(&code_hash(&env, "NEWOWNER")).active);
+}
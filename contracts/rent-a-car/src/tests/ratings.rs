@@ -0,0 +1,122 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN, Env};
+use crate::{
+    contract::RentACarContractClient,
+    methods::rating::RATING_WINDOW_SECONDS,
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+fn review(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[9; 32])
+}
+
+fn complete_rental(contract: &RentACarContractClient, renter: &Address, owner: &Address) {
+    contract.rental(renter, owner, &24, &1000_i128, &no_options());
+    contract.end_rental(renter, owner);
+}
+
+#[test]
+pub fn test_both_parties_rate_a_completed_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let other_renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    token_admin.mint(&other_renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    complete_rental(&contract, &renter, &owner);
+    complete_rental(&contract, &other_renter, &owner);
+
+    contract.rate_as_renter(&renter, &0, &5, &4, &review(&env));
+    contract.rate_as_renter(&other_renter, &0, &4, &4, &review(&env));
+    contract.rate_as_owner(&owner, &renter, &0, &3, &review(&env));
+
+    let car = contract.get_car_rating(&owner);
+    assert_eq!(car.count, 2);
+    assert_eq!(car.average_x100, 450);
+    assert_eq!(contract.get_owner_rating(&owner).average_x100, 400);
+
+    let as_renter = contract.get_renter_rating(&renter);
+    assert_eq!(as_renter.count, 1);
+    assert_eq!(as_renter.total, 3);
+    assert_eq!(contract.get_renter_rating(&other_renter).count, 0);
+
+    let record = contract.get_rental_history(&renter, &0, &1).get(0).unwrap();
+    assert!(record.renter_rated);
+    assert!(record.owner_rated);
+}
+
+#[test]
+#[should_panic(expected = "AlreadyRated")]
+pub fn test_rental_can_only_be_rated_once() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    complete_rental(&contract, &renter, &owner);
+
+    contract.rate_as_renter(&renter, &0, &5, &5, &review(&env));
+    contract.rate_as_renter(&renter, &0, &1, &1, &review(&env));
+}
+
+#[test]
+#[should_panic(expected = "RatingWindowClosed")]
+pub fn test_rating_window_closes() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    complete_rental(&contract, &renter, &owner);
+
+    env.ledger().set_timestamp(RATING_WINDOW_SECONDS + 1);
+    contract.rate_as_owner(&owner, &renter, &0, &5, &review(&env));
+}
+
+#[test]
+#[should_panic(expected = "NotOwner")]
+pub fn test_only_the_rental_owner_rates_the_renter() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    complete_rental(&contract, &renter, &owner);
+
+    contract.rate_as_owner(&stranger, &renter, &0, &1, &review(&env));
+}
+
+#[test]
+#[should_panic(expected = "InvalidRating")]
+pub fn test_score_must_be_between_one_and_five() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    complete_rental(&contract, &renter, &owner);
+
+    contract.rate_as_renter(&renter, &0, &6, &5, &review(&env));
+}
@@ -0,0 +1,199 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::{
+    storage::{contract_balance::read_contract_balance, types::early_return::EarlyReturnPolicy},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+#[test]
+pub fn test_referrers_of_renter_and_owner_split_one_share() {
+    let ContractTest { env, contract, token, address, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let renter_referrer = Address::generate(&env);
+    let owner_referrer = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128); // 10% commission
+    contract.set_referral_config(&2000, &0); // 20% of commission
+
+    contract.register_referrer(&renter, &renter_referrer);
+    contract.register_referrer(&owner, &owner_referrer);
+    assert_eq!(contract.get_referrer(&renter), Some(renter_referrer.clone()));
+
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+
+    // Commission 300, the 60 referral share is split between both referrers
+    assert_eq!(contract.get_referral_account(&renter_referrer).balance, 30);
+    assert_eq!(contract.get_referral_account(&owner_referrer).balance, 30);
+    assert_eq!(contract.get_admin_commission_balance(), 240);
+    assert_eq!(contract.get_rental(&renter, &owner).referral_rewards, 60);
+
+    assert_eq!(contract.claim_referral_rewards(&renter_referrer), 30);
+    assert_eq!(token_client.balance(&renter_referrer), 30);
+
+    let account = contract.get_referral_account(&renter_referrer);
+    assert_eq!(account.balance, 0);
+    assert_eq!(account.total_earned, 30);
+
+    let contract_balance = env.as_contract(&address, || read_contract_balance(&env));
+    assert_eq!(contract_balance, 3000 + 240 + 30);
+}
+
+#[test]
+pub fn test_referral_rewards_stop_at_cap() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &20_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128);
+    contract.set_referral_config(&5000, &200);
+    contract.register_referrer(&renter, &referrer);
+
+    // Commission 300, half of it would be 150
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+    assert_eq!(contract.get_referral_account(&referrer).total_earned, 150);
+
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+    contract.end_rental(&renter, &owner);
+    assert_eq!(contract.get_referral_account(&referrer).total_earned, 200);
+    assert_eq!(contract.get_admin_commission_balance(), 600 - 200);
+}
+
+#[test]
+#[should_panic(expected = "Referrer already registered")]
+pub fn test_referrer_can_only_be_registered_once() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let user = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.register_referrer(&user, &referrer);
+    contract.register_referrer(&user, &other);
+}
+
+#[test]
+pub fn test_early_return_after_commission_withdrawal_refunds_base_only() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128);
+    contract.set_early_return_policy(&owner, &EarlyReturnPolicy::ProRated);
+
+    let start = env.ledger().timestamp();
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+    contract.withdraw_admin_commissions();
+
+    env.ledger().set_timestamp(start + 86400);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 3300 + 2000);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 1000);
+}
+
+#[test]
+pub fn test_early_return_keeps_referral_rewards_out_of_other_commission() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let other_renter = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    token_admin.mint(&other_renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128);
+    contract.set_referral_config(&5000, &0);
+    contract.register_referrer(&renter, &referrer);
+
+    // Another rental's commission sits in the admin balance
+    contract.rental(&other_renter, &owner, &24, &1000_i128, &no_options());
+    contract.end_rental(&other_renter, &owner);
+    assert_eq!(contract.get_admin_commission_balance(), 100);
+
+    contract.set_early_return_policy(&owner, &EarlyReturnPolicy::ProRated);
+
+    // Commission 300, 150 of it goes to the referrer
+    let start = env.ledger().timestamp();
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+    assert_eq!(contract.get_admin_commission_balance(), 250);
+
+    // Two unused days refund 2/3 of the 150 the admin kept, not of the full 300
+    env.ledger().set_timestamp(start + 86400);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 3300 + 2000 + 100);
+    assert_eq!(contract.get_admin_commission_balance(), 150);
+    assert_eq!(contract.get_referral_account(&referrer).balance, 150);
+}
+
+#[test]
+pub fn test_referral_rewards_ignore_car_payout_destination() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let alice = Address::generate(&env);
+    let mallory = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &alice, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128); // 10% commission
+    contract.set_referral_config(&2000, &0);
+    contract.register_referrer(&renter, &alice);
+
+    // Alice sells her car, the buyer points that car's payouts at themselves
+    contract.transfer(&alice, &mallory, &contract.get_car_token(&alice));
+    contract.set_payout_destination(&alice, &mallory);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+
+    assert_eq!(contract.claim_referral_rewards(&alice), 20);
+    assert_eq!(token_client.balance(&alice), 20);
+    assert_eq!(token_client.balance(&mallory), 0);
+}
+
+#[test]
+pub fn test_referral_rewards_follow_the_car_holder() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let owner_referrer = Address::generate(&env);
+    let buyer_referrer = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128); // 10% commission
+    contract.set_referral_config(&5000, &0);
+    contract.register_referrer(&owner, &owner_referrer);
+    contract.register_referrer(&buyer, &buyer_referrer);
+
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+
+    // Commission 300, the referral share goes to the referrer of the new holder
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+    assert_eq!(contract.get_referral_account(&buyer_referrer).balance, 150);
+    assert_eq!(contract.get_referral_account(&owner_referrer).balance, 0);
+}
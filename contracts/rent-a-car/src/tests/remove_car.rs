@@ -1,14 +1,17 @@
 use soroban_sdk::{testutils::Address as _, Address, String};
-use crate::{storage::car::has_car, tests::config::contract::ContractTest};
+use crate::{
+    storage::car::has_car,
+    tests::config::{contract::ContractTest, utils::add_test_car},
+};
 
 #[test]
 pub fn test_remove_car_deletes_from_storage() {
     let ContractTest { env, contract, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
-    let brand = String::from_slice(&env, "TestBrand");
-    let model = String::from_slice(&env, "TestModel");
-    let color = String::from_slice(&env, "Black");
+    let brand = String::from_str(&env, "TestBrand");
+    let model = String::from_str(&env, "TestModel");
+    let color = String::from_str(&env, "Black");
     let passengers = 4_u32;
     let ac = true;
     let price_per_day = 1500_i128;
@@ -26,3 +29,22 @@ pub fn test_remove_car_deletes_from_storage() {
         has_car(&env, &owner)
     }));
 }
+
+#[test]
+pub fn test_relisted_car_starts_without_previous_settings() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let destination = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_payout_destination(&owner, &destination);
+    contract.set_streaming_settlement(&owner, &true);
+
+    contract.remove_car(&owner);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    assert_eq!(contract.get_payout_destination(&owner), owner);
+    assert!(!contract.get_streaming_settlement(&owner));
+}
@@ -1,5 +1,5 @@
 use soroban_sdk::{testutils::Address as _, Address};
-use crate::{storage::{car::read_car, contract_balance::read_contract_balance, rental::read_rental, types::car_status::CarStatus}, tests::config::contract::ContractTest};
+use crate::{storage::{car::read_car, contract_balance::read_contract_balance, rental::read_rental, types::car_status::CarStatus}, tests::config::{contract::ContractTest, utils::no_options}};
 
 #[test]
 pub fn test_rental_car_successfully() {
@@ -9,7 +9,7 @@ pub fn test_rental_car_successfully() {
     let renter = Address::generate(&env);
     let price_per_day = 1500_i128;
     let commission_percentage = 0_i128; // 0% commission for this test
-    let total_days = 3;
+    let duration_hours = 72; // 3 days
     let amount = 4500_i128;
     
     let (_, token_admin, _) = token;
@@ -18,9 +18,9 @@ pub fn test_rental_car_successfully() {
     env.mock_all_auths();
     token_admin.mint(&renter, &amount_mint);
 
-    let brand = soroban_sdk::String::from_slice(&env, "TestBrand");
-    let model = soroban_sdk::String::from_slice(&env, "TestModel");
-    let color = soroban_sdk::String::from_slice(&env, "Black");
+    let brand = soroban_sdk::String::from_str(&env, "TestBrand");
+    let model = soroban_sdk::String::from_str(&env, "TestModel");
+    let color = soroban_sdk::String::from_str(&env, "Black");
     let passengers = 4_u32;
     let ac = true;
 
@@ -31,7 +31,7 @@ pub fn test_rental_car_successfully() {
     assert_eq!(initial_contract_balance, 0);
 
     env.mock_all_auths();
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &owner, &duration_hours, &amount, &no_options());
 
     // 💰 Depósito + Comisión: El balance del contrato incluye el depósito y la comisión
     // Como la comisión por defecto es 0, el balance será igual al amount
@@ -43,11 +43,10 @@ pub fn test_rental_car_successfully() {
     assert_eq!(car.available_to_withdraw, amount);
 
     let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner).unwrap());
-    assert_eq!(rental.total_days_to_rent, total_days);
+    assert_eq!(rental.duration_hours, duration_hours);
     assert_eq!(rental.amount, amount);
-    assert!(rental.start_time >= 0);
     assert!(rental.end_time > rental.start_time);
-    // Verify end_time is approximately start_time + (days * 86400 seconds)
-    let expected_end_time = rental.start_time + (total_days as u64 * 86400);
+    // Verify end_time is start_time + (hours * 3600 seconds)
+    let expected_end_time = rental.start_time + (duration_hours as u64 * 3600);
     assert_eq!(rental.end_time, expected_end_time);
 }
@@ -0,0 +1,99 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::{
+    storage::types::early_return::EarlyReturnPolicy,
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+#[test]
+pub fn test_active_rentals_are_indexed_per_renter() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner_1 = Address::generate(&env);
+    let owner_2 = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner_1, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &owner_2, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner_1, &24, &1000_i128, &no_options());
+    contract.rental(&renter, &owner_2, &24, &1000_i128, &no_options());
+
+    let active = contract.get_active_rentals(&renter);
+    assert_eq!(active.len(), 2);
+    assert!(active.contains(&owner_1));
+    assert!(active.contains(&owner_2));
+
+    contract.end_rental(&renter, &owner_1);
+
+    let active = contract.get_active_rentals(&renter);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0), Some(owner_2.clone()));
+
+    contract.end_rental(&renter, &owner_2);
+    assert!(contract.get_active_rentals(&renter).is_empty());
+}
+
+#[test]
+pub fn test_completed_rentals_are_archived_with_final_amounts() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128); // 10% commission
+    contract.set_early_return_policy(&owner, &EarlyReturnPolicy::ProRated);
+
+    contract.rental(&renter, &owner, &72, &3000_i128, &no_options());
+    env.ledger().set_timestamp(1_000 + 86_400);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(contract.get_rental_history_count(&renter), 1);
+    let record = contract.get_rental_history(&renter, &0, &10).get(0).unwrap();
+    assert_eq!(record.owner, owner);
+    assert_eq!(record.start_time, 1_000);
+    assert_eq!(record.end_time, 1_000 + 3 * 86_400);
+    assert_eq!(record.returned_at, 1_000 + 86_400);
+    assert_eq!(record.amount, 3000);
+    assert_eq!(record.commission, 300);
+    assert_eq!(record.refund, 2000);
+    assert_eq!(record.commission_refund, 200);
+    assert!(!contract.has_rental(&renter, &owner));
+}
+
+#[test]
+pub fn test_rental_history_is_paginated() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    for i in 0..5_u64 {
+        env.ledger().set_timestamp(i * 100_000);
+        contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+        contract.end_rental(&renter, &owner);
+    }
+
+    assert_eq!(contract.get_rental_history_count(&renter), 5);
+
+    let page = contract.get_rental_history(&renter, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().start_time, 100_000);
+
+    let page = contract.get_rental_history(&renter, &4, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().start_time, 400_000);
+
+    assert!(contract.get_rental_history(&renter, &5, &2).is_empty());
+}
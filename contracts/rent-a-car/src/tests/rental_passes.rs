@@ -0,0 +1,134 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+use rental_pass::{RentalPassContract, RentalPassContractClient};
+use crate::{
+    contract::RentACarContractClient,
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+const DAY: u64 = 24 * 60 * 60;
+
+/// Deploys a pass contract linked to `contract`
+fn setup_passes<'a>(env: &Env, contract: &RentACarContractClient, admin: &Address) -> RentalPassContractClient<'a> {
+    let pass_id = env.register(RentalPassContract, (admin,));
+    let passes = RentalPassContractClient::new(env, &pass_id);
+    passes.set_rent_a_car(&contract.address);
+    contract.set_rental_pass_contract(&pass_id);
+    passes
+}
+
+#[test]
+pub fn test_rental_mints_pass_for_rental_window() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    let passes = setup_passes(&env, &contract, &admin);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+
+    let rental = contract.get_rental(&renter, &owner);
+    let pass_id = rental.pass_id.unwrap();
+    assert_eq!(passes.owner_of(&pass_id), renter);
+    assert_eq!(passes.balance(&renter), 1);
+    assert_eq!(passes.get_holder_pass(&renter, &owner), Some(pass_id));
+
+    let pass = passes.get_pass(&pass_id);
+    assert_eq!(pass.car, owner);
+    assert_eq!(pass.car_token_id, contract.get_car_token(&owner));
+    assert_eq!(pass.valid_from, rental.start_time);
+    assert_eq!(pass.valid_until, rental.end_time);
+    assert!(passes.is_valid(&pass_id, &env.ledger().timestamp()));
+}
+
+#[test]
+pub fn test_extension_moves_pass_end() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    let passes = setup_passes(&env, &contract, &admin);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    contract.extend_rental(&renter, &owner, &24, &1000_i128);
+
+    let rental = contract.get_rental(&renter, &owner);
+    let pass_id = rental.pass_id.unwrap();
+    assert_eq!(passes.get_pass(&pass_id).valid_until, rental.end_time);
+    assert!(passes.is_valid(&pass_id, &(rental.start_time + DAY + 1)));
+}
+
+#[test]
+pub fn test_end_rental_burns_pass() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    let passes = setup_passes(&env, &contract, &admin);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    let pass_id = contract.get_rental(&renter, &owner).pass_id.unwrap();
+
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(passes.balance(&renter), 0);
+    assert_eq!(passes.get_holder_pass(&renter, &owner), None);
+    assert!(!passes.is_valid(&pass_id, &env.ledger().timestamp()));
+}
+
+#[test]
+pub fn test_relinking_keeps_running_passes() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    let old_passes = setup_passes(&env, &contract, &admin);
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+
+    let new_passes = setup_passes(&env, &contract, &admin);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(old_passes.balance(&renter), 0);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    assert_eq!(new_passes.balance(&renter), 1);
+}
+
+#[test]
+pub fn test_rentals_without_pass_contract() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    assert_eq!(contract.get_rental(&renter, &owner).pass_id, None);
+    assert_eq!(contract.get_rental_pass_contract(), None);
+
+    contract.end_rental(&renter, &owner);
+}
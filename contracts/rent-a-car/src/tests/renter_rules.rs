@@ -0,0 +1,141 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN, Env};
+use crate::{
+    contract::RentACarContractClient,
+    methods::dispute::CLAIM_WINDOW_SECONDS,
+    storage::{structs::renter_rules::RenterRules, types::reputation_tier::ReputationTier},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+fn deposit_rules(base_deposit: i128) -> RenterRules {
+    RenterRules {
+        min_rating_x100: 0,
+        min_completed_rentals: 0,
+        base_deposit,
+        new_multiplier_bps: 20000,
+        bronze_multiplier_bps: 15000,
+        silver_multiplier_bps: 10000,
+        gold_multiplier_bps: 0,
+    }
+}
+
+/// Completes `count` one-day rentals of `owner`'s car, each rated `score` by the owner
+fn build_history(env: &Env, contract: &RentACarContractClient, renter: &Address, owner: &Address, count: u32, score: u32) {
+    let start = contract.get_rental_history_count(renter);
+    for index in start..start + count {
+        contract.rental(renter, owner, &24, &1000_i128, &no_options());
+        contract.end_rental(renter, owner);
+        contract.rate_as_owner(owner, renter, &index, &score, &BytesN::from_array(env, &[0; 32]));
+    }
+}
+
+#[test]
+pub fn test_deposit_scales_with_reputation_tier() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let history_car = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &100_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &history_car, 1000_i128, 0_i128);
+    contract.set_renter_rules(&owner, &deposit_rules(500));
+
+    assert_eq!(contract.get_reputation_tier(&renter), ReputationTier::New);
+    assert_eq!(contract.get_required_deposit(&renter, &owner), 1000);
+
+    build_history(&env, &contract, &renter, &history_car, 1, 3);
+    assert_eq!(contract.get_reputation_tier(&renter), ReputationTier::Bronze);
+    assert_eq!(contract.get_required_deposit(&renter, &owner), 750);
+
+    build_history(&env, &contract, &renter, &history_car, 2, 5);
+    assert_eq!(contract.get_reputation_tier(&renter), ReputationTier::Silver);
+
+    // Deposit is charged on top of the rental and held through the damage claim window
+    let before = token_client.balance(&renter);
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+    assert_eq!(contract.get_rental(&renter, &owner).deposit, 500);
+    assert_eq!(token_client.balance(&renter), before - 1000 - 500);
+
+    contract.end_rental(&renter, &owner);
+    assert_eq!(token_client.balance(&renter), before - 1000 - 500);
+
+    let record = contract.get_rental_history(&renter, &3, &1).get(0).unwrap();
+    assert_eq!(record.deposit, 500);
+    assert!(!record.deposit_settled);
+
+    env.ledger().with_mut(|li| li.timestamp += CLAIM_WINDOW_SECONDS + 1);
+    contract.release_deposit(&renter, &3);
+    assert_eq!(token_client.balance(&renter), before - 1000);
+}
+
+#[test]
+pub fn test_gold_renters_can_skip_deposit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let history_car = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &100_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &history_car, 1000_i128, 0_i128);
+    contract.set_renter_rules(&owner, &deposit_rules(500));
+
+    build_history(&env, &contract, &renter, &history_car, 10, 5);
+    assert_eq!(contract.get_reputation_tier(&renter), ReputationTier::Gold);
+    assert_eq!(contract.get_required_deposit(&renter, &owner), 0);
+}
+
+#[test]
+#[should_panic(expected = "NotEnoughRentals")]
+pub fn test_car_can_require_completed_rentals() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_renter_rules(&owner, &RenterRules { min_completed_rentals: 1, ..deposit_rules(0) });
+
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "RatingTooLow")]
+pub fn test_car_can_require_minimum_rating() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let history_car = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &history_car, 1000_i128, 0_i128);
+    contract.set_renter_rules(&owner, &RenterRules { min_rating_x100: 400, ..deposit_rules(0) });
+
+    build_history(&env, &contract, &renter, &history_car, 1, 2);
+    contract.rental(&renter, &owner, &24, &1000_i128, &no_options());
+}
+
+#[test]
+#[should_panic(expected = "InvalidRenterRules")]
+pub fn test_deposit_multiplier_is_bounded() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_renter_rules(&owner, &RenterRules { new_multiplier_bps: 200_000, ..deposit_rules(100) });
+}
@@ -0,0 +1,127 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Vec};
+use crate::{
+    storage::{contract_balance::read_contract_balance, structs::price_rules::PriceRules},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+const DAY: u64 = 86400;
+const HOUR: u64 = 3600;
+const SATURDAY: u64 = 1704499200; // 2024-01-06 00:00 UTC
+
+#[test]
+pub fn test_streaming_rental_accrues_and_refunds_early_return() {
+    let ContractTest { env, contract, token, address, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128); // 10% commission
+    contract.set_streaming_settlement(&owner, &true);
+
+    let start = env.ledger().timestamp();
+    contract.rental(&renter, &owner, &96, &4000_i128, &no_options());
+    assert_eq!(token_client.balance(&renter), 5600);
+
+    // Nothing is credited up front
+    assert_eq!(contract.get_available_to_withdraw(&owner), 0);
+
+    env.ledger().set_timestamp(start + DAY);
+    assert_eq!(contract.get_streamed_claimable(&renter, &owner), 1000);
+    assert_eq!(contract.claim_streamed(&owner, &renter), 1000);
+    assert_eq!(token_client.balance(&owner), 1000);
+    assert_eq!(contract.get_streamed_claimable(&renter, &owner), 0);
+
+    // Return after 3 of the 4 days: one day of base price and its commission go back
+    env.ledger().set_timestamp(start + 3 * DAY);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(contract.get_available_to_withdraw(&owner), 2000);
+    assert_eq!(token_client.balance(&renter), 5600 + 1100);
+    assert_eq!(contract.get_admin_commission_balance(), 300);
+
+    let contract_balance = env.as_contract(&address, || read_contract_balance(&env));
+    assert_eq!(contract_balance, 2000 + 300);
+}
+
+#[test]
+pub fn test_streaming_rental_returned_late_earns_full_amount() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_streaming_settlement(&owner, &true);
+
+    let start = env.ledger().timestamp();
+    contract.rental(&renter, &owner, &48, &2000_i128, &no_options());
+
+    env.ledger().set_timestamp(start + 5 * DAY);
+    assert_eq!(contract.get_streamed_claimable(&renter, &owner), 2000);
+
+    contract.end_rental(&renter, &owner);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 2000);
+    assert_eq!(token_client.balance(&renter), 8000);
+}
+
+#[test]
+#[should_panic(expected = "Rental is not streaming")]
+pub fn test_claim_streamed_requires_streaming_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    contract.rental(&renter, &owner, &48, &2000_i128, &no_options());
+    contract.claim_streamed(&owner, &renter);
+}
+
+#[test]
+pub fn test_streaming_extension_into_cheaper_day_keeps_accrual() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_streaming_settlement(&owner, &true);
+    contract.set_price_rules(&owner, &PriceRules {
+        weekend_surcharge_bps: 5000,
+        seasons: Vec::new(&env),
+        weekly_discount_bps: 0,
+        monthly_discount_bps: 0,
+    });
+
+    // Saturday and Sunday at the weekend rate
+    env.ledger().set_timestamp(SATURDAY);
+    contract.rental(&renter, &owner, &48, &3000_i128, &no_options());
+
+    // Claimed on Sunday evening, then extended into a cheaper Monday
+    env.ledger().set_timestamp(SATURDAY + 44 * HOUR);
+    assert_eq!(contract.claim_streamed(&owner, &renter), 2750);
+    contract.extend_rental(&renter, &owner, &24, &1000_i128);
+    assert_eq!(contract.get_streamed_claimable(&renter, &owner), 0);
+
+    // Monday noon: the whole weekend and half of Monday have accrued
+    env.ledger().set_timestamp(SATURDAY + 60 * HOUR);
+    assert_eq!(contract.get_streamed_claimable(&renter, &owner), 3500 - 2750);
+
+    contract.end_rental(&renter, &owner);
+    assert_eq!(token_client.balance(&renter), 10_000 - 3500);
+    assert_eq!(token_client.balance(&owner), 2750);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 750);
+}
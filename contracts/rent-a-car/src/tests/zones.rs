@@ -0,0 +1,173 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+use crate::{
+    contract::RentACarContractClient,
+    storage::structs::{beneficiary::Beneficiary, car_filter::CarFilter, rental_options::RentalOptions},
+    tests::config::{contract::ContractTest, utils::{add_test_car, no_options}},
+};
+
+const BUENOS_AIRES: u32 = 1;
+const CORDOBA: u32 = 2;
+
+fn setup_zones(env: &Env, contract: &RentACarContractClient) {
+    contract.add_zone(&BUENOS_AIRES, &String::from_str(env, "Buenos Aires"), &String::from_str(env, "Aeroparque"));
+    contract.add_zone(&CORDOBA, &String::from_str(env, "Cordoba"), &String::from_str(env, "Terminal"));
+}
+
+fn in_zone(env: &Env, zone_id: u32) -> CarFilter {
+    CarFilter {
+        statuses: Vec::new(env),
+        min_passengers: 0,
+        ac: None,
+        min_price: 0,
+        max_price: 0,
+        brand: None,
+        zone: Some(zone_id),
+    }
+}
+
+#[test]
+pub fn test_discovery_filters_by_zone() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let porteno = Address::generate(&env);
+    let cordobes = Address::generate(&env);
+    let unassigned = Address::generate(&env);
+
+    env.mock_all_auths();
+    setup_zones(&env, &contract);
+    add_test_car(&env, &contract, &porteno, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &cordobes, 1000_i128, 0_i128);
+    add_test_car(&env, &contract, &unassigned, 1000_i128, 0_i128);
+
+    contract.set_car_zone(&porteno, &BUENOS_AIRES, &String::from_str(&env, "Palermo garage"));
+    contract.set_car_zone(&cordobes, &CORDOBA, &String::from_str(&env, ""));
+
+    let location = contract.get_car_location(&cordobes);
+    assert_eq!(location.zone_id, CORDOBA);
+    assert_eq!(location.pickup_point, String::from_str(&env, "Terminal"));
+
+    let page = contract.list_cars(&in_zone(&env, BUENOS_AIRES), &0, &10);
+    assert_eq!(page.cars.len(), 1);
+    assert_eq!(page.cars.get(0).unwrap().owner, porteno);
+
+    // Moving the car updates the zone index
+    contract.set_car_zone(&porteno, &CORDOBA, &String::from_str(&env, ""));
+    assert!(contract.list_cars(&in_zone(&env, BUENOS_AIRES), &0, &10).cars.is_empty());
+    assert_eq!(contract.list_cars(&in_zone(&env, CORDOBA), &0, &10).cars.len(), 2);
+}
+
+#[test]
+pub fn test_one_way_rental_charges_fee_and_moves_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    setup_zones(&env, &contract);
+    add_test_car(&env, &contract, &owner, 1000_i128, 1000_i128); // 10% commission
+    contract.set_car_zone(&owner, &BUENOS_AIRES, &String::from_str(&env, "Palermo garage"));
+    contract.set_relocation_fee(&BUENOS_AIRES, &CORDOBA, &500_i128);
+
+    let options = RentalOptions { return_zone: Some(CORDOBA), ..no_options() };
+    contract.rental(&renter, &owner, &48, &2000_i128, &options);
+
+    // Fee is charged on top of amount + commission and goes to the owner without commission
+    assert_eq!(token_client.balance(&renter), 10_000 - 2000 - 200 - 500);
+    assert_eq!(contract.get_rental(&renter, &owner).relocation_fee, 500);
+    assert_eq!(contract.get_available_to_withdraw(&owner), 2500);
+
+    contract.end_rental(&renter, &owner);
+
+    let location = contract.get_car_location(&owner);
+    assert_eq!(location.zone_id, CORDOBA);
+    assert_eq!(location.pickup_point, String::from_str(&env, "Terminal"));
+    assert!(contract.list_cars(&in_zone(&env, BUENOS_AIRES), &0, &10).cars.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Return zone must differ from the pickup zone")]
+pub fn test_return_zone_must_differ_from_pickup_zone() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    setup_zones(&env, &contract);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_car_zone(&owner, &BUENOS_AIRES, &String::from_str(&env, ""));
+
+    let options = RentalOptions { return_zone: Some(BUENOS_AIRES), ..no_options() };
+    contract.rental(&renter, &owner, &24, &1000_i128, &options);
+}
+
+#[test]
+#[should_panic(expected = "One-way rentals are not offered between these zones")]
+pub fn test_one_way_rental_requires_configured_fee() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    setup_zones(&env, &contract);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_car_zone(&owner, &BUENOS_AIRES, &String::from_str(&env, ""));
+    contract.set_relocation_fee(&BUENOS_AIRES, &CORDOBA, &500_i128);
+    contract.remove_relocation_fee(&BUENOS_AIRES, &CORDOBA);
+
+    let options = RentalOptions { return_zone: Some(CORDOBA), ..no_options() };
+    contract.rental(&renter, &owner, &24, &1000_i128, &options);
+}
+
+#[test]
+#[should_panic(expected = "InvalidCarDetails")]
+pub fn test_pickup_point_length_is_bounded() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    setup_zones(&env, &contract);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+
+    let too_long = String::from_bytes(&env, &[b'a'; 129]);
+    contract.set_car_zone(&owner, &BUENOS_AIRES, &too_long);
+}
+
+#[test]
+pub fn test_relocation_fee_split_among_co_owners() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+
+    env.mock_all_auths();
+    token_admin.mint(&renter, &10_000_i128);
+    setup_zones(&env, &contract);
+    add_test_car(&env, &contract, &owner, 1000_i128, 0_i128);
+    contract.set_car_zone(&owner, &BUENOS_AIRES, &String::from_str(&env, ""));
+    contract.set_relocation_fee(&BUENOS_AIRES, &CORDOBA, &500_i128);
+    contract.set_car_beneficiaries(&owner, &vec![
+        &env,
+        Beneficiary { address: owner.clone(), share_bps: 6000 },
+        Beneficiary { address: partner.clone(), share_bps: 4000 },
+    ]);
+
+    let options = RentalOptions { return_zone: Some(CORDOBA), ..no_options() };
+    contract.rental(&renter, &owner, &48, &2000_i128, &options);
+    contract.end_rental(&renter, &owner);
+
+    assert_eq!(contract.get_available_to_withdraw(&owner), 0);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &owner), 1500);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &partner), 1000);
+}
@@ -0,0 +1,153 @@
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use stellar_tokens::non_fungible::{burnable::emit_burn, Base};
+use crate::interface::contract::RentalPassContractTrait;
+use crate::events::pass::PassExtended;
+use crate::storage::{
+    admin::{read_admin, read_rent_a_car, write_admin, write_rent_a_car},
+    pass::{read_holder_pass, read_pass, remove_holder_pass, remove_pass, write_holder_pass, write_pass},
+    structs::rental_pass::RentalPass,
+    types::errors::RentalPassError,
+};
+
+const TOKEN_NAME: &str = "Stellar Motors Rental Pass";
+const TOKEN_SYMBOL: &str = "SMPASS";
+
+/// Only the linked rent-a-car contract issues, extends and burns passes
+fn require_rent_a_car(env: &Env) {
+    let rent_a_car = read_rent_a_car(env).unwrap_or_else(|e| panic!("{:?}", e));
+    rent_a_car.require_auth();
+}
+
+#[contract]
+pub struct RentalPassContract;
+
+#[contractimpl]
+impl RentalPassContractTrait for RentalPassContract {
+    fn __constructor(env: &Env, admin: Address) {
+        write_admin(env, &admin);
+        // Pass data lives on-chain in `get_pass`, there is no off-chain metadata
+        Base::set_metadata(
+            env,
+            String::from_str(env, ""),
+            String::from_str(env, TOKEN_NAME),
+            String::from_str(env, TOKEN_SYMBOL),
+        );
+    }
+
+    fn get_admin(env: &Env) -> Address {
+        read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"))
+    }
+
+    fn set_rent_a_car(env: &Env, rent_a_car: Address) {
+        let admin = read_admin(env).unwrap_or_else(|_| panic!("Contract not initialized"));
+        admin.require_auth();
+
+        write_rent_a_car(env, &rent_a_car);
+    }
+
+    fn get_rent_a_car(env: &Env) -> Address {
+        read_rent_a_car(env).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    fn mint(env: &Env, to: Address, car: Address, car_token_id: u32, valid_from: u64, valid_until: u64) -> u32 {
+        require_rent_a_car(env);
+
+        if valid_until < valid_from {
+            panic!("{:?}", RentalPassError::InvalidWindow);
+        }
+
+        let token_id = Base::sequential_mint(env, &to);
+        write_pass(env, token_id, &RentalPass {
+            holder: to.clone(),
+            car: car.clone(),
+            car_token_id,
+            valid_from,
+            valid_until,
+        });
+        write_holder_pass(env, &to, &car, token_id);
+
+        token_id
+    }
+
+    fn set_valid_until(env: &Env, token_id: u32, valid_until: u64) {
+        require_rent_a_car(env);
+
+        let mut pass = read_pass(env, token_id).unwrap_or_else(|e| panic!("{:?}", e));
+        if valid_until < pass.valid_from {
+            panic!("{:?}", RentalPassError::InvalidWindow);
+        }
+
+        pass.valid_until = valid_until;
+        write_pass(env, token_id, &pass);
+
+        PassExtended { token_id, valid_until }.publish(env);
+    }
+
+    fn burn(env: &Env, token_id: u32) {
+        require_rent_a_car(env);
+
+        let pass = read_pass(env, token_id).unwrap_or_else(|e| panic!("{:?}", e));
+        remove_pass(env, token_id);
+        remove_holder_pass(env, &pass.holder, &pass.car);
+        Base::update(env, Some(&pass.holder), None, token_id);
+        emit_burn(env, &pass.holder, token_id);
+    }
+
+    fn balance(env: &Env, account: Address) -> u32 {
+        Base::balance(env, &account)
+    }
+
+    fn owner_of(env: &Env, token_id: u32) -> Address {
+        Base::owner_of(env, token_id)
+    }
+
+    fn name(env: &Env) -> String {
+        Base::name(env)
+    }
+
+    fn symbol(env: &Env) -> String {
+        Base::symbol(env)
+    }
+
+    fn token_uri(env: &Env, token_id: u32) -> String {
+        Base::token_uri(env, token_id)
+    }
+
+    fn get_approved(env: &Env, token_id: u32) -> Option<Address> {
+        Base::get_approved(env, token_id)
+    }
+
+    fn is_approved_for_all(env: &Env, owner: Address, operator: Address) -> bool {
+        Base::is_approved_for_all(env, &owner, &operator)
+    }
+
+    fn transfer(_env: &Env, _from: Address, _to: Address, _token_id: u32) {
+        panic!("{:?}", RentalPassError::NonTransferable);
+    }
+
+    fn transfer_from(_env: &Env, _spender: Address, _from: Address, _to: Address, _token_id: u32) {
+        panic!("{:?}", RentalPassError::NonTransferable);
+    }
+
+    fn approve(_env: &Env, _approver: Address, _approved: Address, _token_id: u32, _live_until_ledger: u32) {
+        panic!("{:?}", RentalPassError::NonTransferable);
+    }
+
+    fn approve_for_all(_env: &Env, _owner: Address, _operator: Address, _live_until_ledger: u32) {
+        panic!("{:?}", RentalPassError::NonTransferable);
+    }
+
+    fn get_pass(env: &Env, token_id: u32) -> RentalPass {
+        read_pass(env, token_id).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    fn is_valid(env: &Env, token_id: u32, timestamp: u64) -> bool {
+        read_pass(env, token_id)
+            .map(|pass| pass.valid_from <= timestamp && timestamp < pass.valid_until)
+            .unwrap_or(false)
+    }
+
+    fn get_holder_pass(env: &Env, holder: Address, car: Address) -> Option<u32> {
+        read_holder_pass(env, &holder, &car)
+    }
+}
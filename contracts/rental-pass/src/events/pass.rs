@@ -0,0 +1,11 @@
+use soroban_sdk::contractevent;
+
+/// Emitted when a rental extension moves the end of a pass
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PassExtended {
+    #[topic]
+    pub token_id: u32,
+    pub valid_until: u64,
+}
+
@@ -0,0 +1,32 @@
+use soroban_sdk::{Address, Env, String};
+use crate::storage::structs::rental_pass::RentalPass;
+
+pub trait RentalPassContractTrait {
+    fn __constructor(env: &Env, admin: Address);
+    fn get_admin(env: &Env) -> Address;
+    fn set_rent_a_car(env: &Env, rent_a_car: Address);
+    fn get_rent_a_car(env: &Env) -> Address;
+
+    // Issued by rent-a-car
+    fn mint(env: &Env, to: Address, car: Address, car_token_id: u32, valid_from: u64, valid_until: u64) -> u32;
+    fn set_valid_until(env: &Env, token_id: u32, valid_until: u64);
+    fn burn(env: &Env, token_id: u32);
+
+    // Token queries
+    fn balance(env: &Env, account: Address) -> u32;
+    fn owner_of(env: &Env, token_id: u32) -> Address;
+    fn name(env: &Env) -> String;
+    fn symbol(env: &Env) -> String;
+    fn token_uri(env: &Env, token_id: u32) -> String;
+    fn get_approved(env: &Env, token_id: u32) -> Option<Address>;
+    fn is_approved_for_all(env: &Env, owner: Address, operator: Address) -> bool;
+    fn transfer(env: &Env, from: Address, to: Address, token_id: u32);
+    fn transfer_from(env: &Env, spender: Address, from: Address, to: Address, token_id: u32);
+    fn approve(env: &Env, approver: Address, approved: Address, token_id: u32, live_until_ledger: u32);
+    fn approve_for_all(env: &Env, owner: Address, operator: Address, live_until_ledger: u32);
+
+    // Verification
+    fn get_pass(env: &Env, token_id: u32) -> RentalPass;
+    fn is_valid(env: &Env, token_id: u32, timestamp: u64) -> bool;
+    fn get_holder_pass(env: &Env, holder: Address, car: Address) -> Option<u32>;
+}
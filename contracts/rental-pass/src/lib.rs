@@ -0,0 +1,9 @@
+#![no_std]
+
+mod contract;
+mod events;
+mod interface;
+mod storage;
+mod tests;
+
+pub use contract::{RentalPassContract, RentalPassContractClient};
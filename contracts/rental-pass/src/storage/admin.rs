@@ -0,0 +1,26 @@
+use soroban_sdk::{Address, Env};
+
+use super::types::storage::DataKey;
+use super::types::errors::RentalPassError;
+
+pub(crate) fn read_admin(env: &Env) -> Result<Address, RentalPassError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(RentalPassError::NotInitialized)
+}
+
+pub(crate) fn write_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub(crate) fn read_rent_a_car(env: &Env) -> Result<Address, RentalPassError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RentACar)
+        .ok_or(RentalPassError::RentACarNotSet)
+}
+
+pub(crate) fn write_rent_a_car(env: &Env, rent_a_car: &Address) {
+    env.storage().instance().set(&DataKey::RentACar, rent_a_car);
+}
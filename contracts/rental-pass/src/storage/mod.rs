@@ -0,0 +1,4 @@
+pub mod admin;
+pub mod pass;
+pub mod structs;
+pub mod types;
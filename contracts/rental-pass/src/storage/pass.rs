@@ -0,0 +1,37 @@
+use soroban_sdk::{Address, Env};
+use stellar_tokens::non_fungible::NonFungibleTokenError;
+
+use crate::storage::{structs::rental_pass::RentalPass, types::storage::DataKey};
+
+pub(crate) fn read_pass(env: &Env, token_id: u32) -> Result<RentalPass, NonFungibleTokenError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Pass(token_id))
+        .ok_or(NonFungibleTokenError::NonExistentToken)
+}
+
+pub(crate) fn write_pass(env: &Env, token_id: u32, pass: &RentalPass) {
+    env.storage().persistent().set(&DataKey::Pass(token_id), pass);
+}
+
+pub(crate) fn remove_pass(env: &Env, token_id: u32) {
+    env.storage().persistent().remove(&DataKey::Pass(token_id));
+}
+
+pub(crate) fn read_holder_pass(env: &Env, holder: &Address, car: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderPass(holder.clone(), car.clone()))
+}
+
+pub(crate) fn write_holder_pass(env: &Env, holder: &Address, car: &Address, token_id: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::HolderPass(holder.clone(), car.clone()), &token_id);
+}
+
+pub(crate) fn remove_holder_pass(env: &Env, holder: &Address, car: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::HolderPass(holder.clone(), car.clone()));
+}
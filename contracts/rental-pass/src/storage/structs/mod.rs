@@ -0,0 +1 @@
+pub mod rental_pass;
@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Proof of an active rental, held by the renter until the car is returned
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RentalPass {
+    pub holder: Address,
+    pub car: Address,          // Car as identified in rent-a-car (the address it was listed under)
+    pub car_token_id: u32,     // Car's ownership token in rent-a-car
+    pub valid_from: u64,       // Rental start
+    pub valid_until: u64,      // Scheduled end, moved by extensions
+}
@@ -0,0 +1,16 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RentalPassError {
+    // General errors
+    NotInitialized = 1,       // Admin not set
+    RentACarNotSet = 2,       // Admin has not linked the rent-a-car contract yet
+
+    // Validation errors
+    InvalidWindow = 10,       // Validity ends before it starts
+
+    // Pass errors
+    NonTransferable = 20,     // Passes stay with the renter they were issued to
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,                      // dirección del administrador
+    RentACar,                   // contrato de alquiler autorizado a emitir y quemar pases
+    Pass(u32),                  // pase emitido para un alquiler activo
+    HolderPass(Address, Address), // (titular, auto) -> id del pase vigente
+}
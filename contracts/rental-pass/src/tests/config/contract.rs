@@ -0,0 +1,27 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use crate::{contract::RentalPassContractClient, RentalPassContract};
+
+pub struct ContractTest<'a> {
+    pub env: Env,
+    pub contract: RentalPassContractClient<'a>,
+    pub admin: Address,
+    /// Stands in for the rent-a-car contract linked to the passes
+    pub rent_a_car: Address,
+}
+
+impl<'a> ContractTest<'a> {
+    pub fn setup() -> Self {
+        let env = Env::default();
+
+        let admin = Address::generate(&env);
+        let rent_a_car = Address::generate(&env);
+
+        let contract_id = env.register(RentalPassContract, (&admin,));
+        let contract = RentalPassContractClient::new(&env, &contract_id);
+
+        env.mock_all_auths();
+        contract.set_rent_a_car(&rent_a_car);
+
+        ContractTest { env, contract, admin, rent_a_car }
+    }
+}
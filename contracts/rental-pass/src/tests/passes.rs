@@ -0,0 +1,120 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use crate::{contract::RentalPassContractClient, RentalPassContract};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+pub fn test_mint_issues_pass_to_renter() {
+    let ContractTest { env, contract, admin, rent_a_car } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let car = Address::generate(&env);
+
+    assert_eq!(contract.get_admin(), admin);
+    assert_eq!(contract.get_rent_a_car(), rent_a_car);
+
+    let token_id = contract.mint(&renter, &car, &7, &1_000, &87_400);
+    assert_eq!(token_id, 0);
+    assert_eq!(contract.owner_of(&0), renter);
+    assert_eq!(contract.balance(&renter), 1);
+    assert_eq!(contract.get_holder_pass(&renter, &car), Some(0));
+    assert_eq!(contract.symbol(), String::from_str(&env, "SMPASS"));
+    assert_eq!(contract.token_uri(&0), String::from_str(&env, ""));
+
+    let pass = contract.get_pass(&0);
+    assert_eq!(pass.car, car);
+    assert_eq!(pass.car_token_id, 7);
+    assert_eq!(pass.valid_until, 87_400);
+}
+
+#[test]
+pub fn test_pass_valid_within_window() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let car = Address::generate(&env);
+    contract.mint(&renter, &car, &0, &1_000, &2_000);
+
+    assert!(!contract.is_valid(&0, &999));
+    assert!(contract.is_valid(&0, &1_000));
+    assert!(!contract.is_valid(&0, &2_000));
+
+    contract.set_valid_until(&0, &3_000);
+    assert!(contract.is_valid(&0, &2_000));
+}
+
+#[test]
+pub fn test_burn_removes_pass() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let car = Address::generate(&env);
+    contract.mint(&renter, &car, &0, &1_000, &2_000);
+
+    contract.burn(&0);
+
+    assert_eq!(contract.balance(&renter), 0);
+    assert_eq!(contract.get_holder_pass(&renter, &car), None);
+    assert!(!contract.is_valid(&0, &1_500));
+}
+
+#[test]
+#[should_panic(expected = "NonTransferable")]
+pub fn test_pass_cannot_be_transferred() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let car = Address::generate(&env);
+    contract.mint(&renter, &car, &0, &1_000, &2_000);
+
+    contract.transfer(&renter, &friend, &0);
+}
+
+#[test]
+#[should_panic(expected = "NonTransferable")]
+pub fn test_pass_cannot_be_approved_for_all() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let car = Address::generate(&env);
+    contract.mint(&renter, &car, &0, &1_000, &2_000);
+
+    contract.approve_for_all(&renter, &operator, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+pub fn test_burned_pass_has_no_owner() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let car = Address::generate(&env);
+    contract.mint(&renter, &car, &0, &1_000, &2_000);
+    contract.burn(&0);
+
+    contract.owner_of(&0);
+}
+
+#[test]
+#[should_panic(expected = "InvalidWindow")]
+pub fn test_window_must_not_end_before_start() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let car = Address::generate(&env);
+    contract.mint(&renter, &car, &0, &2_000, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "RentACarNotSet")]
+pub fn test_minting_requires_linked_rent_a_car() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(RentalPassContract, (&admin,));
+    let contract = RentalPassContractClient::new(&env, &contract_id);
+
+    contract.mint(&admin, &admin, &0, &0, &1);
+}
@@ -254,11 +254,27 @@ export const CarsList = ({ cars }: CarsListProps) => {
     const contractClient =
       await stellarService.buildClient<IRentACarContract>(walletAddress);
 
+    const durationHours = totalDaysToRent * 24;
+
+    // Price rules can make the amount differ from price per day times days, so ask the contract
+    const quoteResponse = await contractClient.quote_rental({
+      owner: car.ownerAddress,
+      start_time: Math.floor(Date.now() / 1000),
+      duration_hours: durationHours,
+    });
+    const amount = Number((quoteResponse as any).result ?? quoteResponse);
+
     const result = await contractClient.rental({
       renter: walletAddress,
       owner: car.ownerAddress,
-      total_days_to_rent: totalDaysToRent,
-      amount: car.pricePerDay * totalDaysToRent * ONE_XLM_IN_STROOPS,
+      duration_hours: durationHours,
+      amount,
+      options: {
+        promo_code: undefined,
+        redeem_points: 0,
+        return_zone: undefined,
+        insured: false,
+      },
     });
     const xdr = result.toXDR();
 
@@ -21,7 +21,7 @@ export const DiscoverCarsForm = ({
   const [discoveredAddresses, setDiscoveredAddresses] = useState<string[]>([]);
   const [error, setError] = useState<string>("");
 
-  // Fetch all car owners from contract
+  // Fetch all car owners from contract, one page at a time
   const fetchAllCarOwners = async () => {
     if (!walletAddress) return;
 
@@ -31,10 +31,29 @@ export const DiscoverCarsForm = ({
       const contractClient =
         await stellarService.buildClient<IRentACarContract>(walletAddress);
       
-      const ownersResponse = await contractClient.get_all_car_owners();
-      // Handle response - could be array directly or wrapped in result
-      const owners = (ownersResponse as any)?.result || ownersResponse || [];
-      
+      // Page through the registry instead of fetching every owner in one call
+      const owners: string[] = [];
+      let cursor: number | undefined = 0;
+      while (cursor !== undefined) {
+        const pageResponse = await contractClient.list_cars({
+          filter: {
+            statuses: [],
+            min_passengers: 0,
+            ac: undefined,
+            min_price: 0,
+            max_price: 0,
+            brand: undefined,
+            zone: undefined,
+          },
+          cursor,
+          limit: 50,
+        });
+        // Handle response - could be the page directly or wrapped in result
+        const page = (pageResponse as any)?.result || pageResponse;
+        owners.push(...(page?.cars ?? []).map((listing: any) => listing.owner));
+        cursor = page?.next_cursor ?? undefined;
+      }
+
       if (Array.isArray(owners) && owners.length > 0) {
         // Convert addresses to strings if needed
         const addressStrings = owners.map((addr: any) => {
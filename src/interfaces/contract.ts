@@ -61,18 +61,38 @@ export interface IRentACarContract extends IBaseContractClient {
     car_status: CarStatus;
     available_to_withdraw: number;
     commission_percentage: number;
+    price_per_hour: number;
+    min_duration_hours: number;
+    max_duration_hours: number;
   }>;
 
+  quote_rental: ({
+    owner,
+    start_time,
+    duration_hours,
+  }: {
+    owner: string;
+    start_time: number; // Unix timestamp in seconds
+    duration_hours: number;
+  }) => Promise<number>; // Base amount in stroops, without commission
+
   rental: ({
     renter,
     owner,
-    total_days_to_rent,
+    duration_hours,
     amount,
+    options,
   }: {
     renter: string;
     owner: string;
-    total_days_to_rent: number;
+    duration_hours: number; // Whole days are 24 hours
     amount: number;
+    options: {
+      promo_code: string | undefined;
+      redeem_points: number;
+      return_zone: number | undefined;
+      insured: boolean;
+    };
   }) => Promise<this>;
 
   remove_car: ({ owner }: { owner: string }) => Promise<this>;
@@ -100,10 +120,28 @@ export interface IRentACarContract extends IBaseContractClient {
     renter: string;
     owner: string;
   }) => Promise<{
-    total_days_to_rent: number;
+    holder: string;
+    duration_hours: number;
     amount: number;
     start_time: number;
     end_time: number;
+    commission: number;
+    referral_rewards: number;
+    streaming: boolean;
+    claimed: number;
+    discount: number;
+    points_redeemed: number;
+    return_zone: number | undefined;
+    relocation_fee: number;
+    deposit: number;
+    premium: number;
+    coverage: number;
+    insurance_pool: string | undefined;
+    pass_contract: string | undefined;
+    pass_id: number | undefined;
+    extensions: { start_time: number; end_time: number; amount: number }[];
+    additional_drivers: string[];
+    driver_fees: number;
   }>;
 
   get_available_to_withdraw: ({
@@ -117,4 +155,29 @@ export interface IRentACarContract extends IBaseContractClient {
   withdraw_admin_commissions: () => Promise<this>;
 
   get_all_car_owners: () => Promise<string[]>;
+
+  list_cars: ({
+    filter,
+    cursor,
+    limit,
+  }: {
+    filter: {
+      statuses: CarStatus[];
+      min_passengers: number;
+      ac: boolean | undefined;
+      min_price: number;
+      max_price: number;
+      brand: string | undefined;
+      zone: number | undefined;
+    };
+    cursor: number;
+    limit: number;
+  }) => Promise<{
+    cars: {
+      owner: string;
+      car: Record<string, unknown>;
+    }[];
+    details: Map<string, Record<string, unknown>>; // Keyed by owner, missing until the owner publishes details
+    next_cursor: number | undefined;
+  }>;
 }